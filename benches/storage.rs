@@ -0,0 +1,287 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use leptos::*;
+use leptos_controls::Controls;
+
+macro_rules! wide {
+    ($(#[$meta:meta])* $ident:ident { $($field:ident),* $(,)? }) => {
+        #[derive(Clone, Default, Controls)]
+        $(#[$meta])*
+        struct $ident {
+            $($field: String,)*
+        }
+    };
+}
+
+wide! {
+    #[controls(storage = "field")]
+    FieldForm {
+    f0,
+    f1,
+    f2,
+    f3,
+    f4,
+    f5,
+    f6,
+    f7,
+    f8,
+    f9,
+    f10,
+    f11,
+    f12,
+    f13,
+    f14,
+    f15,
+    f16,
+    f17,
+    f18,
+    f19,
+    f20,
+    f21,
+    f22,
+    f23,
+    f24,
+    f25,
+    f26,
+    f27,
+    f28,
+    f29,
+    f30,
+    f31,
+    f32,
+    f33,
+    f34,
+    f35,
+    f36,
+    f37,
+    f38,
+    f39,
+    f40,
+    f41,
+    f42,
+    f43,
+    f44,
+    f45,
+    f46,
+    f47,
+    f48,
+    f49,
+    f50,
+    f51,
+    f52,
+    f53,
+    f54,
+    f55,
+    f56,
+    f57,
+    f58,
+    f59,
+    f60,
+    f61,
+    f62,
+    f63,
+    f64,
+    f65,
+    f66,
+    f67,
+    f68,
+    f69,
+    f70,
+    f71,
+    f72,
+    f73,
+    f74,
+    f75,
+    f76,
+    f77,
+    f78,
+    f79,
+    f80,
+    f81,
+    f82,
+    f83,
+    f84,
+    f85,
+    f86,
+    f87,
+    f88,
+    f89,
+    f90,
+    f91,
+    f92,
+    f93,
+    f94,
+    f95,
+    f96,
+    f97,
+    f98,
+    f99
+    }
+}
+
+wide! {
+    #[controls(storage = "single")]
+    SingleForm {
+    f0,
+    f1,
+    f2,
+    f3,
+    f4,
+    f5,
+    f6,
+    f7,
+    f8,
+    f9,
+    f10,
+    f11,
+    f12,
+    f13,
+    f14,
+    f15,
+    f16,
+    f17,
+    f18,
+    f19,
+    f20,
+    f21,
+    f22,
+    f23,
+    f24,
+    f25,
+    f26,
+    f27,
+    f28,
+    f29,
+    f30,
+    f31,
+    f32,
+    f33,
+    f34,
+    f35,
+    f36,
+    f37,
+    f38,
+    f39,
+    f40,
+    f41,
+    f42,
+    f43,
+    f44,
+    f45,
+    f46,
+    f47,
+    f48,
+    f49,
+    f50,
+    f51,
+    f52,
+    f53,
+    f54,
+    f55,
+    f56,
+    f57,
+    f58,
+    f59,
+    f60,
+    f61,
+    f62,
+    f63,
+    f64,
+    f65,
+    f66,
+    f67,
+    f68,
+    f69,
+    f70,
+    f71,
+    f72,
+    f73,
+    f74,
+    f75,
+    f76,
+    f77,
+    f78,
+    f79,
+    f80,
+    f81,
+    f82,
+    f83,
+    f84,
+    f85,
+    f86,
+    f87,
+    f88,
+    f89,
+    f90,
+    f91,
+    f92,
+    f93,
+    f94,
+    f95,
+    f96,
+    f97,
+    f98,
+    f99
+    }
+}
+
+fn create(c: &mut Criterion) {
+    let mut group = c.benchmark_group("create");
+    group.bench_function("field", |b| {
+        b.iter_batched(
+            FieldForm::default,
+            |value| {
+                let runtime = create_runtime();
+                black_box(FieldFormControls::new(value));
+                runtime.dispose();
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.bench_function("single", |b| {
+        b.iter_batched(
+            SingleForm::default,
+            |value| {
+                let runtime = create_runtime();
+                black_box(SingleFormControls::new(value));
+                runtime.dispose();
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn snapshot(c: &mut Criterion) {
+    let runtime = create_runtime();
+    let field = FieldFormControls::new(FieldForm::default());
+    let single = SingleFormControls::new(SingleForm::default());
+    let mut group = c.benchmark_group("snapshot");
+    group.bench_function("field", |b| b.iter(|| black_box(field.snapshot())));
+    group.bench_function("single", |b| b.iter(|| black_box(single.snapshot())));
+    group.finish();
+    runtime.dispose();
+}
+
+fn update(c: &mut Criterion) {
+    let runtime = create_runtime();
+    let field = FieldFormControls::new(FieldForm::default());
+    let single = SingleFormControls::new(SingleForm::default());
+    let mut group = c.benchmark_group("update");
+    group.bench_function("field", |b| {
+        b.iter(|| {
+            field.f50.set(black_box(String::from("value")));
+            black_box(field.f50.get())
+        })
+    });
+    group.bench_function("single", |b| {
+        b.iter(|| {
+            single.f50.set(black_box(String::from("value")));
+            black_box(single.f50.get())
+        })
+    });
+    group.finish();
+    runtime.dispose();
+}
+
+criterion_group!(benches, create, snapshot, update);
+criterion_main!(benches);
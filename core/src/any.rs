@@ -0,0 +1,72 @@
+use crate::field::Field;
+use crate::state::FieldState;
+use crate::widget::Widget;
+use std::any::Any;
+use std::borrow::Cow;
+
+///
+/// 类型擦除的字段
+///
+/// 不同类型的字段可以保存在同一个集合中,通过字段枚举或者名称统一访问控制器的字段。
+///
+pub trait AnyField: Field {
+    ///
+    /// 值是否已经变更(追踪)
+    ///
+    fn is_dirty(&self) -> bool;
+
+    ///
+    /// 是否已经失去过焦点(追踪)
+    ///
+    fn touched(&self) -> bool;
+
+    ///
+    /// 重置为默认值并清空字段状态
+    ///
+    fn reset(&self) {
+        self.set_default();
+    }
+
+    ///
+    /// 获取字段值的字符串(追踪),类型没有实现`Display`时为空
+    ///
+    fn value_string(&self) -> Option<String>;
+
+    ///
+    /// 从字符串解析并设置字段的值,类型没有实现`FromStr`或者字段只读时返回错误
+    ///
+    fn set_value_string(&self, value: &str) -> Result<(), Cow<'static, str>>;
+
+    ///
+    /// 是否只读
+    ///
+    fn readonly(&self) -> bool {
+        false
+    }
+
+    ///
+    /// 输入控件类型
+    ///
+    fn widget(&self) -> Widget {
+        Widget::Text
+    }
+
+    ///
+    /// 下拉选择框的选项值
+    ///
+    fn options(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    ///
+    /// 字段的交互状态,没有使用[`FieldState`]的字段为空
+    ///
+    fn field_state(&self) -> Option<FieldState> {
+        None
+    }
+
+    ///
+    /// 转换为`Any`,可以向下转换为具体的字段类型
+    ///
+    fn as_any(&self) -> &dyn Any;
+}
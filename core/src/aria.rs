@@ -0,0 +1,120 @@
+use crate::field::Field;
+use leptos::*;
+use std::rc::Rc;
+
+///
+/// 字段关联元素的id
+///
+/// id由前缀和字段名称组成,例如`user-profile-name`,服务端渲染和客户端水合时得到相同的id。
+/// 前缀默认为[`Controls::ID`](crate::Controls::ID),同一页面存在多个相同控制器的表单时需要使用不同的前缀。
+///
+#[derive(Debug, Clone)]
+pub struct FieldIds {
+    id: Rc<str>,
+}
+
+impl FieldIds {
+    pub fn new(prefix: &str, name: &str) -> Self {
+        Self {
+            id: Rc::from(format!("{}-{}", prefix, name)),
+        }
+    }
+
+    ///
+    /// 输入元素的id
+    ///
+    pub fn input(&self) -> String {
+        self.id.to_string()
+    }
+
+    ///
+    /// 标签元素的id
+    ///
+    pub fn label(&self) -> String {
+        format!("{}-label", self.id)
+    }
+
+    ///
+    /// 错误信息元素的id
+    ///
+    pub fn error(&self) -> String {
+        format!("{}-error", self.id)
+    }
+
+    ///
+    /// 帮助信息元素的id
+    ///
+    pub fn help(&self) -> String {
+        format!("{}-help", self.id)
+    }
+
+    ///
+    /// 输入元素描述的id,包括存在时的帮助信息和校验错误时的错误信息
+    ///
+    pub(crate) fn described_by(&self, help: bool, invalid: bool) -> Option<String> {
+        match (help, invalid) {
+            (true, true) => Some(format!("{} {}", self.help(), self.error())),
+            (true, false) => Some(self.help()),
+            (false, true) => Some(self.error()),
+            (false, false) => None,
+        }
+    }
+
+    ///
+    /// 标签元素的属性: `id`和关联输入元素的`for`
+    ///
+    pub fn label_attrs(&self) -> Vec<(&'static str, Attribute)> {
+        vec![
+            ("id", self.label().into_attribute()),
+            ("for", self.input().into_attribute()),
+        ]
+    }
+
+    ///
+    /// 输入元素的属性: `id`、`aria-required`,以及根据校验错误设置的`aria-invalid`和关联帮助信息、错误信息的`aria-describedby`
+    ///
+    pub fn input_attrs<F>(&self, field: F) -> Vec<(&'static str, Attribute)>
+    where
+        F: Field + Copy + 'static,
+    {
+        self.aria_attrs(field.required(), field.help().is_some(), move || {
+            field.error().is_some()
+        })
+    }
+
+    ///
+    /// 根据是否必须、是否存在帮助信息和是否存在校验错误生成输入元素的属性
+    ///
+    pub(crate) fn aria_attrs(
+        &self,
+        required: bool,
+        help: bool,
+        invalid: impl Fn() -> bool + Clone + 'static,
+    ) -> Vec<(&'static str, Attribute)> {
+        let describedby = {
+            let ids = self.clone();
+            let invalid = invalid.clone();
+            move || ids.described_by(help, invalid())
+        };
+        vec![
+            ("id", self.input().into_attribute()),
+            ("aria-required", required.then_some("true").into_attribute()),
+            (
+                "aria-invalid",
+                (move || invalid().to_string()).into_attribute(),
+            ),
+            ("aria-describedby", describedby.into_attribute()),
+        ]
+    }
+
+    ///
+    /// 错误信息元素的属性: `id`,并且变更时通知辅助技术
+    ///
+    pub fn error_attrs(&self) -> Vec<(&'static str, Attribute)> {
+        vec![
+            ("id", self.error().into_attribute()),
+            ("role", "alert".into_attribute()),
+            ("aria-live", "polite".into_attribute()),
+        ]
+    }
+}
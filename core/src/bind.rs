@@ -0,0 +1,207 @@
+use crate::any::AnyField;
+use leptos::html::AnyElement;
+use leptos::*;
+use std::borrow::Cow;
+use std::rc::Rc;
+
+const INVALID_NUMBER: &str = "请输入有效的数字!";
+
+///
+/// 绑定的元素类型
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BindKind {
+    Text,
+    Number,
+    Checked,
+    Select,
+}
+
+///
+/// 字段和原生元素的绑定
+///
+/// [`attrs`](FieldBinding::attrs)生成元素的值和`aria`属性,服务端渲染时同样输出;[`bind`]指令在浏览器中同步元素的属性并监听输入事件。
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_controls_core::*;
+/// # use std::borrow::Cow;
+/// # struct Age;
+/// # impl FieldMeta for Age {
+/// #     type Type = u32;
+/// #     const LABEL: &'static str = "年龄";
+/// #     const REQUIRED: bool = true;
+/// #     const VALIDATE: fn(&u32) -> Option<Cow<'static, str>> = |_| None;
+/// #     const DISPLAY: fn(&u32) -> Option<String> = |age| Some(age.to_string());
+/// #     const PARSE: fn(&str) -> Result<u32, Cow<'static, str>> =
+/// #         |value| value.parse().map_err(|_| Cow::Borrowed("年龄无效"));
+/// # }
+/// # let runtime = create_runtime();
+/// let age = RwSignalField::<Age, u32>::new(18);
+/// let binding = age.bind_number();
+/// let html = view! { <input type="number" {..binding.attrs()} use:bind=binding.clone() /> }
+///     .into_view()
+///     .render_to_string();
+/// assert!(html.contains(r#"value="18""#));
+///
+/// // 数字输入框的值按照数字解析
+/// binding.input("1e2").unwrap();
+/// assert_eq!(age.get_untracked(), 100);
+/// assert!(binding.input("abc").is_err());
+/// assert_eq!(age.get_untracked(), 100);
+/// # runtime.dispose();
+/// ```
+///
+#[derive(Clone)]
+pub struct FieldBinding {
+    kind: BindKind,
+    field: Rc<dyn AnyField>,
+}
+
+///
+/// 创建字段和原生元素的绑定
+///
+/// 字段的值通过`Display`和`FromStr`和元素的值互相转换,所有实现了[`AnyField`]的字段都可以使用。
+///
+pub trait BindField: AnyField + Copy + 'static {
+    ///
+    /// 绑定文本输入框或者多行文本输入框,输入时更新字段的值
+    ///
+    fn bind_text(&self) -> FieldBinding {
+        FieldBinding::new(BindKind::Text, *self)
+    }
+
+    ///
+    /// 绑定数字输入框,输入的值无法解析为有限的数字时保持字段的值不变
+    ///
+    fn bind_number(&self) -> FieldBinding {
+        FieldBinding::new(BindKind::Number, *self)
+    }
+
+    ///
+    /// 绑定复选框,字段的值为`true`时选中
+    ///
+    fn bind_checked(&self) -> FieldBinding {
+        FieldBinding::new(BindKind::Checked, *self)
+    }
+
+    ///
+    /// 绑定下拉选择框,选项的`value`为字段值的字符串
+    ///
+    fn bind_select(&self) -> FieldBinding {
+        FieldBinding::new(BindKind::Select, *self)
+    }
+}
+
+impl<F: AnyField + Copy + 'static> BindField for F {}
+
+impl FieldBinding {
+    fn new(kind: BindKind, field: impl AnyField + 'static) -> Self {
+        Self {
+            kind,
+            field: Rc::new(field),
+        }
+    }
+
+    ///
+    /// 元素的属性: 字段的值(复选框为`checked`)、`aria-required`和根据校验错误设置的`aria-invalid`
+    ///
+    /// 属性在服务端渲染时输出,浏览器中输入后元素的值由[`bind`]指令通过`property`同步。
+    ///
+    pub fn attrs(&self) -> Vec<(&'static str, Attribute)> {
+        let value = {
+            let field = self.field.clone();
+            move || field.value_string().unwrap_or_default()
+        };
+        let value = match self.kind {
+            BindKind::Checked => ("checked", (move || value() == "true").into_attribute()),
+            BindKind::Text | BindKind::Number | BindKind::Select => {
+                ("value", value.into_attribute())
+            }
+        };
+        let invalid = {
+            let field = self.field.clone();
+            move || field.error().is_some().to_string()
+        };
+        vec![
+            value,
+            (
+                "aria-required",
+                self.field.required().then_some("true").into_attribute(),
+            ),
+            ("aria-invalid", invalid.into_attribute()),
+        ]
+    }
+
+    ///
+    /// 将元素的值写入字段,复选框的值为`true`或`false`
+    ///
+    /// 数字输入框的值先按照数字解析,空值和无法解析为有限数字的值返回错误并保持字段的值不变,
+    /// 整数字段可以接收科学计数法或者小数部分为零的值。
+    ///
+    pub fn input(&self, value: &str) -> Result<(), Cow<'static, str>> {
+        match self.kind {
+            BindKind::Text | BindKind::Checked | BindKind::Select => {
+                self.field.set_value_string(value)
+            }
+            BindKind::Number => {
+                let value = value.trim();
+                let number = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|number| number.is_finite())
+                    .ok_or(Cow::Borrowed(INVALID_NUMBER))?;
+                self.field.set_value_string(value).or_else(|error| {
+                    if number.fract() == 0.0 {
+                        self.field.set_value_string(&format!("{:.0}", number))
+                    } else {
+                        Err(error)
+                    }
+                })
+            }
+        }
+    }
+}
+
+///
+/// 绑定字段的指令
+///
+/// 同步元素的值并监听输入事件,失去焦点时标记字段已经失去焦点,并设置`aria-required`和根据校验错误设置的`aria-invalid`。
+/// 指令只在浏览器中执行,服务端渲染需要同时展开[`FieldBinding::attrs`]。
+///
+pub fn bind(el: HtmlElement<AnyElement>, binding: FieldBinding) {
+    let value = {
+        let field = binding.field.clone();
+        move || field.value_string().unwrap_or_default()
+    };
+    let input = {
+        let binding = binding.clone();
+        move |value: String| {
+            let _ = binding.input(&value);
+        }
+    };
+    let el = match binding.kind {
+        BindKind::Text | BindKind::Number => el
+            .prop("value", value)
+            .on(ev::input, move |ev| input(event_target_value(&ev))),
+        BindKind::Select => el
+            .prop("value", value)
+            .on(ev::change, move |ev| input(event_target_value(&ev))),
+        BindKind::Checked => el
+            .prop("checked", move || value() == "true")
+            .on(ev::change, move |ev| {
+                input(event_target_checked(&ev).to_string())
+            }),
+    };
+    let field = binding.field;
+    let invalid = {
+        let field = field.clone();
+        move || field.error().is_some().to_string()
+    };
+    let required = field.required().then_some("true");
+    // 元素已经挂载,属性和事件直接作用于DOM节点
+    let _ = el
+        .attr("aria-required", required)
+        .attr("aria-invalid", invalid)
+        .on(ev::blur, move |_| field.touch());
+}
@@ -0,0 +1,101 @@
+use crate::any::AnyField;
+use crate::error::ValidationErrors;
+use crate::form::FormState;
+use std::borrow::Cow;
+
+///
+/// 控制器
+///
+/// 派生宏生成的控制器都实现了该特征,可以编写不依赖具体控制器类型的通用组件,例如对话框、提交栏等。
+///
+pub trait Controls: Copy + 'static {
+    ///
+    /// 控制器对应的值类型
+    ///
+    type Value;
+
+    ///
+    /// 所有字段的名称,按照声明顺序排列
+    ///
+    const FIELDS: &'static [&'static str];
+
+    ///
+    /// 字段关联元素id的前缀,派生宏使用类型名称的kebab-case,例如`UserProfile`为`user-profile`
+    ///
+    const ID: &'static str;
+
+    ///
+    /// 使用初始值创建控制器
+    ///
+    fn new(value: Self::Value) -> Self;
+
+    ///
+    /// 所有字段设置为默认值
+    ///
+    fn set_default(&self);
+
+    ///
+    /// 获取所有字段的值(不追踪)
+    ///
+    fn snapshot(&self) -> Self::Value;
+
+    ///
+    /// 校验所有字段并返回错误信息,控制器会被标记为已提交
+    ///
+    fn validate(&self) -> Vec<Cow<'static, str>>;
+
+    ///
+    /// 通过字段名称获取字段,名称不存在时为空
+    ///
+    fn get_field(&self, name: &str) -> Option<&dyn AnyField>;
+
+    ///
+    /// 所有字段共享的表单状态
+    ///
+    fn form(&self) -> FormState;
+
+    ///
+    /// 是否存在值已经变更的字段(追踪)
+    ///
+    fn is_dirty(&self) -> bool;
+
+    ///
+    /// 根据校验模式获取需要展示的所有错误信息(追踪)
+    ///
+    fn errors(&self) -> Vec<Cow<'static, str>>;
+
+    ///
+    /// 销毁控制器的所有信号
+    ///
+    fn dispose(&self);
+
+    ///
+    /// 聚焦到第一个校验失败并且注册了元素引用的字段,返回是否聚焦成功,默认不聚焦任何字段
+    ///
+    fn focus_first_error(&self) -> bool {
+        false
+    }
+
+    ///
+    /// 校验成功时获取所有字段的值
+    ///
+    fn try_snapshot(&self) -> Result<Self::Value, ValidationErrors> {
+        let errors = self.validate();
+        if errors.is_empty() {
+            Ok(self.snapshot())
+        } else {
+            Err(ValidationErrors::from(errors))
+        }
+    }
+
+    ///
+    /// 提交表单,校验成功时获取所有字段的值,失败时聚焦到第一个校验失败的字段
+    ///
+    fn submit(&self) -> Result<Self::Value, ValidationErrors> {
+        let result = self.try_snapshot();
+        if result.is_err() {
+            self.focus_first_error();
+        }
+        result
+    }
+}
@@ -0,0 +1,142 @@
+//!
+//! 字段值和字符串的转换
+//!
+//! 派生宏通过自动引用的方法查找规则选择实现:类型实现了`Display`/`FromStr`时使用对应的转换,
+//! 否则使用不支持转换的实现。没有配置输入控件的字段同样根据是否实现了`SelectOptions`选择控件,
+//! 字段值的比较同样根据是否实现了`PartialEq`选择。
+//!
+
+use crate::widget::{SelectOptions, Widget};
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+const UNSUPPORTED: &str = "不支持字符串转换!";
+
+pub struct DisplayWrap<'a, T>(pub &'a T);
+
+pub trait ViaDisplay {
+    fn to_value_string(&self) -> Option<String>;
+}
+
+impl<T: Display> ViaDisplay for DisplayWrap<'_, T> {
+    fn to_value_string(&self) -> Option<String> {
+        Some(self.0.to_string())
+    }
+}
+
+pub trait NoDisplay {
+    fn to_value_string(&self) -> Option<String>;
+}
+
+impl<T> NoDisplay for &DisplayWrap<'_, T> {
+    fn to_value_string(&self) -> Option<String> {
+        None
+    }
+}
+
+pub struct ParseWrap<T>(pub PhantomData<T>);
+
+pub trait ViaFromStr<T> {
+    fn parse_value(&self, value: &str) -> Result<T, Cow<'static, str>>;
+}
+
+impl<T> ViaFromStr<T> for ParseWrap<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn parse_value(&self, value: &str) -> Result<T, Cow<'static, str>> {
+        value
+            .parse::<T>()
+            .map_err(|error| Cow::Owned(error.to_string()))
+    }
+}
+
+pub trait NoFromStr<T> {
+    fn parse_value(&self, value: &str) -> Result<T, Cow<'static, str>>;
+}
+
+impl<T> NoFromStr<T> for &ParseWrap<T> {
+    fn parse_value(&self, _: &str) -> Result<T, Cow<'static, str>> {
+        Err(Cow::Borrowed(UNSUPPORTED))
+    }
+}
+
+pub struct SelectWrap<T>(pub PhantomData<T>);
+
+pub trait ViaSelectOptions {
+    fn widget(&self) -> Widget;
+
+    fn options(&self) -> Vec<String>;
+}
+
+impl<T: SelectOptions + Display> ViaSelectOptions for SelectWrap<T> {
+    fn widget(&self) -> Widget {
+        Widget::Select
+    }
+
+    fn options(&self) -> Vec<String> {
+        T::options().iter().map(ToString::to_string).collect()
+    }
+}
+
+pub trait NoSelectOptions {
+    fn widget(&self) -> Widget;
+
+    fn options(&self) -> Vec<String>;
+}
+
+impl<T> NoSelectOptions for &SelectWrap<T> {
+    fn widget(&self) -> Widget {
+        Widget::Text
+    }
+
+    fn options(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+pub struct EqWrap<'a, T>(pub &'a T, pub &'a T);
+
+pub trait ViaPartialEq {
+    fn value_eq(&self) -> bool;
+}
+
+impl<T: PartialEq> ViaPartialEq for EqWrap<'_, T> {
+    fn value_eq(&self) -> bool {
+        self.0 == self.1
+    }
+}
+
+pub trait NoPartialEq {
+    fn value_eq(&self) -> bool;
+}
+
+impl<T> NoPartialEq for &EqWrap<'_, T> {
+    fn value_eq(&self) -> bool {
+        false
+    }
+}
+
+///
+/// 不支持转换时的默认实现
+///
+pub fn unsupported_display<T>(_: &T) -> Option<String> {
+    None
+}
+
+///
+/// 不支持转换时的默认实现
+///
+pub fn unsupported_parse<T>(_: &str) -> Result<T, Cow<'static, str>> {
+    Err(Cow::Borrowed(UNSUPPORTED))
+}
+
+///
+/// 不支持比较时的默认实现,总是视为不相等
+///
+pub fn unsupported_eq<T>(_: &T, _: &T) -> bool {
+    false
+}
@@ -0,0 +1,121 @@
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+
+///
+/// 校验错误信息集合
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationErrors(Vec<Cow<'static, str>>);
+
+impl ValidationErrors {
+    pub fn new(errors: Vec<Cow<'static, str>>) -> Self {
+        Self(errors)
+    }
+
+    ///
+    /// 添加错误信息
+    ///
+    pub fn push(&mut self, error: impl Into<Cow<'static, str>>) {
+        self.0.push(error.into());
+    }
+
+    ///
+    /// 获取全部错误信息
+    ///
+    pub fn into_inner(self) -> Vec<Cow<'static, str>> {
+        self.0
+    }
+}
+
+impl Deref for ValidationErrors {
+    type Target = [Cow<'static, str>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for ValidationErrors {
+    type Item = Cow<'static, str>;
+    type IntoIter = std::vec::IntoIter<Cow<'static, str>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a Cow<'static, str>;
+    type IntoIter = std::slice::Iter<'a, Cow<'static, str>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.join("|"))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl From<Vec<Cow<'static, str>>> for ValidationErrors {
+    fn from(errors: Vec<Cow<'static, str>>) -> Self {
+        Self(errors)
+    }
+}
+
+impl From<Cow<'static, str>> for ValidationErrors {
+    fn from(error: Cow<'static, str>) -> Self {
+        Self(vec![error])
+    }
+}
+
+impl From<&'static str> for ValidationErrors {
+    fn from(error: &'static str) -> Self {
+        Self(vec![Cow::from(error)])
+    }
+}
+
+impl From<String> for ValidationErrors {
+    fn from(error: String) -> Self {
+        Self(vec![Cow::from(error)])
+    }
+}
+
+impl From<Infallible> for ValidationErrors {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
+///
+/// 未知的字段名称
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField(String);
+
+impl UnknownField {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    ///
+    /// 未知的字段名称
+    ///
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for UnknownField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown field `{}`", self.0)
+    }
+}
+
+impl std::error::Error for UnknownField {}
@@ -20,4 +20,70 @@ pub trait Field {
     /// 设置默认值
     ///
     fn set_default(&self);
+
+    ///
+    /// 根据校验模式获取需要展示的校验错误信息(追踪),默认不展示
+    ///
+    fn error(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    ///
+    /// 标记字段已经失去焦点,默认忽略
+    ///
+    fn touch(&self) {}
+
+    ///
+    /// 重新校验并根据校验模式展示校验错误信息,默认忽略
+    ///
+    fn revalidate(&self) {}
+
+    ///
+    /// 聚焦到字段关联的元素,返回是否聚焦成功,默认没有关联的元素
+    ///
+    fn focus(&self) -> bool {
+        false
+    }
+
+    ///
+    /// 追踪字段值的变更,控制器的`on_change`、`depends_on`和`watch_any`通过该方法监听字段
+    ///
+    /// 默认不追踪,自定义字段没有实现时这些监听不会收到该字段的变更。
+    ///
+    fn track(&self) {}
+
+    ///
+    /// 输入提示
+    ///
+    fn placeholder(&self) -> Option<&'static str> {
+        None
+    }
+
+    ///
+    /// 帮助信息
+    ///
+    fn help(&self) -> Option<&'static str> {
+        None
+    }
+
+    ///
+    /// 自动渲染时是否隐藏
+    ///
+    fn hidden(&self) -> bool {
+        false
+    }
+
+    ///
+    /// 自动渲染时的顺序
+    ///
+    fn order(&self) -> i32 {
+        0
+    }
+
+    ///
+    /// 输入元素的自定义属性
+    ///
+    fn attrs(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
 }
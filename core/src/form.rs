@@ -0,0 +1,97 @@
+use crate::mode::ValidationMode;
+use leptos::*;
+
+///
+/// 控制器的表单状态,由控制器的所有字段共享
+///
+#[derive(Clone, Copy)]
+pub struct FormState {
+    mode: ValidationMode,
+    submitted: RwSignal<bool>,
+    syncing: StoredValue<bool>,
+}
+
+impl FormState {
+    pub fn new(mode: ValidationMode) -> Self {
+        Self {
+            mode,
+            submitted: create_rw_signal(false),
+            syncing: store_value(false),
+        }
+    }
+
+    ///
+    /// 校验模式
+    ///
+    pub fn mode(&self) -> ValidationMode {
+        self.mode
+    }
+
+    ///
+    /// 是否已经提交过
+    ///
+    pub fn submitted(&self) -> bool {
+        self.submitted.get()
+    }
+
+    ///
+    /// 标记为已提交
+    ///
+    pub fn submit(&self) {
+        if !self.submitted.get_untracked() {
+            self.submitted.set(true);
+        }
+    }
+
+    ///
+    /// 重置提交状态
+    ///
+    pub fn reset(&self) {
+        self.submitted.set(false);
+    }
+
+    ///
+    /// 是否正在和绑定的外部信号同步,同步期间不会执行字段的值变更回调和依赖变更的处理
+    ///
+    pub fn syncing(&self) -> bool {
+        self.syncing.try_get_value().unwrap_or_default()
+    }
+
+    ///
+    /// 在和外部信号同步的状态下执行,嵌套调用时保持同步状态
+    ///
+    pub fn sync<O>(&self, f: impl FnOnce() -> O) -> O {
+        let syncing = self.syncing();
+        self.syncing.try_set_value(true);
+        let result = f();
+        self.syncing.try_set_value(syncing);
+        result
+    }
+
+    ///
+    /// 提供表单状态的上下文,不需要知道控制器的具体类型即可获取
+    ///
+    pub fn provide(&self) {
+        provide_context(*self);
+    }
+
+    ///
+    /// 获取上层提供的表单状态
+    ///
+    pub fn use_context() -> Option<Self> {
+        use_context::<Self>()
+    }
+
+    ///
+    /// 获取上层提供的表单状态,不存在时会panic
+    ///
+    pub fn expect_context() -> Self {
+        expect_context::<Self>()
+    }
+}
+
+impl Default for FormState {
+    fn default() -> Self {
+        Self::new(ValidationMode::default())
+    }
+}
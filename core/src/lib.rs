@@ -1,11 +1,56 @@
+mod any;
+mod aria;
+mod bind;
+mod controls;
+#[doc(hidden)]
+pub mod convert;
+mod error;
 mod field;
+mod form;
 mod meta;
+mod mode;
+mod owner;
 mod rw_signal;
 mod signal;
+mod slice;
+#[cfg(any(feature = "view", feature = "thaw"))]
+mod slot;
+mod state;
+mod storage;
 #[cfg(feature = "thaw")]
 mod thaw;
+#[cfg(feature = "view")]
+mod view;
+mod widget;
 
+#[cfg(feature = "thaw")]
+#[doc(hidden)]
+pub use ::thaw as __thaw;
+pub use any::AnyField;
+pub use aria::FieldIds;
+pub use bind::{bind, BindField, FieldBinding};
+pub use controls::Controls;
+pub use error::{UnknownField, ValidationErrors};
 pub use field::Field;
+pub use form::FormState;
 pub use meta::FieldMeta;
+pub use mode::ValidationMode;
+pub use owner::ControlsOwner;
 pub use rw_signal::RwSignalField;
 pub use signal::SignalField;
+pub use slice::{SliceField, SliceRoot};
+#[cfg(any(feature = "view", feature = "thaw"))]
+pub use slot::{FieldSlot, FooterSlot};
+pub use state::FieldState;
+pub use storage::FieldStorage;
+#[cfg(feature = "thaw")]
+pub use thaw::{
+    field_invalid, select_options, string_model, FieldError, FieldLabel, FormControls, NumberStep,
+    SubmitBar,
+};
+#[cfg(feature = "thaw")]
+#[doc(hidden)]
+pub use thaw::{NoSelectInput, SelectInput, ViaSelectInput};
+#[cfg(feature = "view")]
+pub use view::AutoForm;
+pub use widget::{SelectOptions, Widget};
@@ -1,3 +1,5 @@
+use crate::mode::ValidationMode;
+use crate::widget::Widget;
 use std::borrow::Cow;
 
 pub trait FieldMeta {
@@ -20,4 +22,60 @@ pub trait FieldMeta {
     /// 校验函数
     ///
     const VALIDATE: fn(&Self::Type) -> Option<Cow<'static, str>>;
+
+    ///
+    /// 校验模式,为空时使用控制器的校验模式
+    ///
+    const MODE: Option<ValidationMode> = None;
+
+    ///
+    /// 转换为字符串,类型没有实现`Display`时为空
+    ///
+    const DISPLAY: fn(&Self::Type) -> Option<String> = crate::convert::unsupported_display;
+
+    ///
+    /// 从字符串解析,类型没有实现`FromStr`时返回错误
+    ///
+    const PARSE: fn(&str) -> Result<Self::Type, Cow<'static, str>> =
+        crate::convert::unsupported_parse;
+
+    ///
+    /// 比较两个值是否相等,类型没有实现`PartialEq`时总是视为不相等
+    ///
+    const EQ: fn(&Self::Type, &Self::Type) -> bool = crate::convert::unsupported_eq;
+
+    ///
+    /// 输入控件类型,未配置时根据字段类型推断
+    ///
+    const WIDGET: fn() -> Widget = || Widget::Text;
+
+    ///
+    /// 下拉选择框的选项值
+    ///
+    const OPTIONS: fn() -> Vec<String> = Vec::new;
+
+    ///
+    /// 输入提示
+    ///
+    const PLACEHOLDER: Option<&'static str> = None;
+
+    ///
+    /// 帮助信息
+    ///
+    const HELP: Option<&'static str> = None;
+
+    ///
+    /// 自动渲染时是否隐藏
+    ///
+    const HIDDEN: bool = false;
+
+    ///
+    /// 自动渲染时的顺序,默认为0,相同时按照声明顺序,没有配置`focus_order`时同样作为聚焦的顺序
+    ///
+    const ORDER: i32 = 0;
+
+    ///
+    /// 输入元素的自定义属性
+    ///
+    const ATTRS: &'static [(&'static str, &'static str)] = &[];
 }
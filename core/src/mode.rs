@@ -0,0 +1,28 @@
+///
+/// 校验模式,决定字段的校验错误信息何时展示
+///
+/// 无论哪种模式,控制器提交(调用`validate`)之后字段都会切换为变更时重新校验。
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationMode {
+    ///
+    /// 字段值变更后校验
+    ///
+    OnChange,
+
+    ///
+    /// 字段失去焦点时校验
+    ///
+    OnBlur,
+
+    ///
+    /// 提交时校验
+    ///
+    #[default]
+    OnSubmit,
+
+    ///
+    /// 字段第一次失去焦点后校验,之后每次变更都会校验
+    ///
+    OnTouched,
+}
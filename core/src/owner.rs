@@ -0,0 +1,54 @@
+use leptos::*;
+
+///
+/// 控制器的响应式所有者
+///
+/// 控制器的信号、监听等都创建在当前所有者的子所有者中,
+/// 当前所有者销毁时控制器随之销毁,也可以通过[`ControlsOwner::dispose`]提前销毁。
+/// 在事件回调等没有所有者的环境中创建的控制器,需要手动销毁。
+///
+#[derive(Clone, Copy)]
+pub struct ControlsOwner {
+    disposer: StoredValue<Option<Disposer>>,
+}
+
+impl ControlsOwner {
+    ///
+    /// 在新的子所有者中执行创建函数
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: 创建参数
+    /// * `f`: 创建函数,参数分别为创建参数和子所有者
+    ///
+    pub fn scope<T, U>(value: T, f: impl Fn(T, ControlsOwner) -> U) -> U
+    where
+        T: 'static,
+    {
+        let owner = ControlsOwner {
+            disposer: store_value(None),
+        };
+        let (result, disposer) = as_child_of_current_owner(move |value| f(value, owner))(value);
+        owner.disposer.set_value(Some(disposer));
+        result
+    }
+
+    ///
+    /// 销毁子所有者及其创建的全部信号
+    ///
+    pub fn dispose(&self) {
+        if let Some(disposer) = self.disposer.try_update_value(Option::take) {
+            drop(disposer);
+            self.disposer.dispose();
+        }
+    }
+
+    ///
+    /// 是否已经销毁
+    ///
+    pub fn is_disposed(&self) -> bool {
+        self.disposer
+            .try_with_value(Option::is_none)
+            .unwrap_or(true)
+    }
+}
@@ -1,8 +1,16 @@
+use crate::any::AnyField;
 use crate::field::Field;
+use crate::form::FormState;
+use crate::state::FieldState;
+use crate::storage::FieldStorage;
+use crate::widget::Widget;
 use crate::FieldMeta;
+use leptos::html::ElementDescriptor;
 use leptos::*;
+use std::any::Any;
 use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 pub struct RwSignalField<M, T>
 where
@@ -10,6 +18,7 @@ where
     M: FieldMeta<Type = T>,
 {
     pub(crate) value: RwSignal<T>,
+    state: FieldState,
     _mark: PhantomData<M>,
 }
 
@@ -21,9 +30,101 @@ where
     pub fn new(value: T) -> Self {
         Self {
             value: create_rw_signal(value),
+            state: FieldState::new(None, M::MODE),
             _mark: PhantomData,
         }
     }
+
+    ///
+    /// 使用控制器的表单状态创建字段
+    ///
+    pub fn with_form(value: T, form: FormState) -> Self {
+        Self {
+            value: create_rw_signal(value),
+            state: FieldState::new(Some(form), M::MODE),
+            _mark: PhantomData,
+        }
+    }
+
+    ///
+    /// 字段的信号,交给组件直接写入时通过监听标记值已经变更
+    ///
+    #[cfg(feature = "thaw")]
+    pub(crate) fn signal(&self) -> RwSignal<T> {
+        let value = self.value;
+        self.state.watch_dirty(move || value.track());
+        value
+    }
+
+    ///
+    /// 写入从外部信号同步的值,通知订阅者但不标记值已经变更
+    ///
+    pub fn sync(&self, value: T) {
+        self.value.set(value);
+    }
+
+    ///
+    /// 字段状态
+    ///
+    pub fn state(&self) -> FieldState {
+        self.state
+    }
+
+    ///
+    /// 创建字段关联元素的引用,控制器的`focus_first_error`会聚焦到该元素
+    ///
+    pub fn node_ref<E>(&self) -> NodeRef<E>
+    where
+        E: ElementDescriptor + Clone + 'static,
+    {
+        self.state.node_ref()
+    }
+
+    ///
+    /// 监听字段值的变更,初始化时不会执行,返回停止监听的函数
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: 变更回调,参数分别为新值和旧值
+    ///
+    pub fn watch(&self, f: impl Fn(&T, Option<&T>) + 'static) -> impl Fn() + Clone {
+        self.watch_with(f, false)
+    }
+
+    ///
+    /// 监听字段值的变更,初始化时立即执行一次,返回停止监听的函数
+    ///
+    pub fn watch_immediate(&self, f: impl Fn(&T, Option<&T>) + 'static) -> impl Fn() + Clone {
+        self.watch_with(f, true)
+    }
+
+    fn watch_with(
+        &self,
+        f: impl Fn(&T, Option<&T>) + 'static,
+        immediate: bool,
+    ) -> impl Fn() + Clone {
+        let value = self.value;
+        let f = Rc::new(f);
+        watch(
+            move || value.get(),
+            move |new, old, _| f(new, old),
+            immediate,
+        )
+    }
+
+    ///
+    /// 是否已经失去过焦点(追踪)
+    ///
+    pub fn touched(&self) -> bool {
+        self.state.touched()
+    }
+
+    ///
+    /// 值是否已经变更(追踪)
+    ///
+    pub fn is_dirty(&self) -> bool {
+        self.state.is_dirty()
+    }
 }
 
 impl<M, T> Field for RwSignalField<M, T>
@@ -45,6 +146,101 @@ where
 
     fn set_default(&self) {
         self.value.set(Default::default());
+        self.state.reset();
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        self.state.error(|| self.value.with(M::VALIDATE))
+    }
+
+    fn touch(&self) {
+        self.state.touch(self.value.with_untracked(M::VALIDATE));
+    }
+
+    fn revalidate(&self) {
+        self.state
+            .revalidate(self.value.with_untracked(M::VALIDATE));
+    }
+
+    fn focus(&self) -> bool {
+        self.state.focus()
+    }
+
+    fn track(&self) {
+        self.value.track();
+    }
+
+    fn placeholder(&self) -> Option<&'static str> {
+        M::PLACEHOLDER
+    }
+
+    fn help(&self) -> Option<&'static str> {
+        M::HELP
+    }
+
+    fn hidden(&self) -> bool {
+        M::HIDDEN
+    }
+
+    fn order(&self) -> i32 {
+        M::ORDER
+    }
+
+    fn attrs(&self) -> &'static [(&'static str, &'static str)] {
+        M::ATTRS
+    }
+}
+
+impl<M, T> AnyField for RwSignalField<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn is_dirty(&self) -> bool {
+        self.state.is_dirty()
+    }
+
+    fn touched(&self) -> bool {
+        self.state.touched()
+    }
+
+    fn value_string(&self) -> Option<String> {
+        self.value.with(M::DISPLAY)
+    }
+
+    fn set_value_string(&self, value: &str) -> Result<(), Cow<'static, str>> {
+        self.set(M::PARSE(value)?);
+        Ok(())
+    }
+
+    fn widget(&self) -> Widget {
+        M::WIDGET()
+    }
+
+    fn options(&self) -> Vec<String> {
+        M::OPTIONS()
+    }
+
+    fn field_state(&self) -> Option<FieldState> {
+        Some(self.state)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<M, T> FieldStorage<T> for RwSignalField<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn create(value: T, form: FormState) -> Self {
+        Self::with_form(value, form)
+    }
+
+    fn snapshot(&self) -> T {
+        self.get_untracked()
     }
 }
 
@@ -110,10 +306,15 @@ where
 
     fn update(&self, f: impl FnOnce(&mut Self::Value)) {
         self.value.update(f);
+        self.state.mark_dirty();
     }
 
     fn try_update<O>(&self, f: impl FnOnce(&mut Self::Value) -> O) -> Option<O> {
-        self.value.try_update(f)
+        let result = self.value.try_update(f);
+        if result.is_some() {
+            self.state.mark_dirty();
+        }
+        result
     }
 }
 impl<M, T> SignalSetUntracked<T> for RwSignalField<M, T>
@@ -138,10 +339,15 @@ where
 
     fn set(&self, new_value: Self::Value) {
         self.value.set(new_value);
+        self.state.mark_dirty();
     }
 
     fn try_set(&self, new_value: Self::Value) -> Option<Self::Value> {
-        self.value.try_set(new_value)
+        let result = self.value.try_set(new_value);
+        if result.is_none() {
+            self.state.mark_dirty();
+        }
+        result
     }
 }
 impl<M, T> SignalGetUntracked for RwSignalField<M, T>
@@ -1,15 +1,75 @@
+use crate::any::AnyField;
 use crate::field::Field;
+use crate::form::FormState;
 use crate::meta::FieldMeta;
+use crate::storage::FieldStorage;
+use crate::widget::Widget;
 use leptos::*;
+use std::any::Any;
 use std::borrow::Cow;
 use std::marker::PhantomData;
 
+///
+/// 只读字段的值
+///
+/// 常量使用`StoredValue`保存,不会创建响应式节点,也不需要追踪。
+///
+enum SignalValue<T: 'static> {
+    Static(StoredValue<T>),
+    Dynamic(Signal<T>),
+}
+
+impl<T: 'static> SignalValue<T> {
+    fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        match self {
+            SignalValue::Static(value) => value.with_value(f),
+            SignalValue::Dynamic(value) => value.with(f),
+        }
+    }
+
+    fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        match self {
+            SignalValue::Static(value) => value.try_with_value(f),
+            SignalValue::Dynamic(value) => value.try_with(f),
+        }
+    }
+
+    fn with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> O {
+        match self {
+            SignalValue::Static(value) => value.with_value(f),
+            SignalValue::Dynamic(value) => value.with_untracked(f),
+        }
+    }
+
+    fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
+        match self {
+            SignalValue::Static(value) => value.try_with_value(f),
+            SignalValue::Dynamic(value) => value.try_with_untracked(f),
+        }
+    }
+
+    fn track(&self) {
+        if let SignalValue::Dynamic(value) = self {
+            value.track();
+        }
+    }
+}
+
+impl<T: 'static> Clone for SignalValue<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for SignalValue<T> {}
+
 pub struct SignalField<M, T>
 where
     M: FieldMeta<Type = T>,
     T: Clone + 'static,
 {
-    pub(crate) value: Signal<T>,
+    value: SignalValue<T>,
+    form: Option<FormState>,
     _mark: PhantomData<M>,
 }
 
@@ -18,12 +78,56 @@ where
     M: FieldMeta<Type = T>,
     T: Clone + 'static,
 {
+    ///
+    /// 创建独立的字段,值保存为常量,不会创建响应式节点
+    ///
     pub fn new(value: T) -> Self {
         Self {
-            value: Signal::derive(move || value.clone()),
+            value: SignalValue::Static(store_value(value)),
+            form: None,
             _mark: PhantomData,
         }
     }
+
+    ///
+    /// 使用外部信号创建独立的字段,字段的值跟随信号变化
+    ///
+    pub fn from_signal(value: Signal<T>) -> Self {
+        Self {
+            value: SignalValue::Dynamic(value),
+            form: None,
+            _mark: PhantomData,
+        }
+    }
+
+    ///
+    /// 使用控制器的表单状态创建字段,值保存为常量
+    ///
+    pub fn with_form(value: T, form: FormState) -> Self {
+        Self {
+            value: SignalValue::Static(store_value(value)),
+            form: Some(form),
+            _mark: PhantomData,
+        }
+    }
+
+    ///
+    /// 使用信号和控制器的表单状态创建字段
+    ///
+    pub fn with_signal(value: Signal<T>, form: FormState) -> Self {
+        Self {
+            value: SignalValue::Dynamic(value),
+            form: Some(form),
+            _mark: PhantomData,
+        }
+    }
+
+    ///
+    /// 字段的值是否为常量
+    ///
+    pub fn is_static(&self) -> bool {
+        matches!(self.value, SignalValue::Static(_))
+    }
 }
 
 impl<M, T> Field for SignalField<M, T>
@@ -44,6 +148,98 @@ where
     }
 
     fn set_default(&self) {}
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        if self.form.is_some_and(|form| form.submitted()) {
+            self.value.with(M::VALIDATE)
+        } else {
+            None
+        }
+    }
+
+    fn touch(&self) {}
+
+    fn revalidate(&self) {}
+
+    fn focus(&self) -> bool {
+        false
+    }
+
+    fn track(&self) {
+        self.value.track();
+    }
+
+    fn placeholder(&self) -> Option<&'static str> {
+        M::PLACEHOLDER
+    }
+
+    fn help(&self) -> Option<&'static str> {
+        M::HELP
+    }
+
+    fn hidden(&self) -> bool {
+        M::HIDDEN
+    }
+
+    fn order(&self) -> i32 {
+        M::ORDER
+    }
+
+    fn attrs(&self) -> &'static [(&'static str, &'static str)] {
+        M::ATTRS
+    }
+}
+
+impl<M, T> AnyField for SignalField<M, T>
+where
+    T: Clone + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn is_dirty(&self) -> bool {
+        false
+    }
+
+    fn touched(&self) -> bool {
+        false
+    }
+
+    fn value_string(&self) -> Option<String> {
+        self.value.with(M::DISPLAY)
+    }
+
+    fn set_value_string(&self, _: &str) -> Result<(), Cow<'static, str>> {
+        Err(Cow::Borrowed("只读字段不能修改!"))
+    }
+
+    fn readonly(&self) -> bool {
+        true
+    }
+
+    fn widget(&self) -> Widget {
+        M::WIDGET()
+    }
+
+    fn options(&self) -> Vec<String> {
+        M::OPTIONS()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<M, T> FieldStorage<T> for SignalField<M, T>
+where
+    T: Clone + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn create(value: T, form: FormState) -> Self {
+        Self::with_form(value, form)
+    }
+
+    fn snapshot(&self) -> T {
+        self.get_untracked()
+    }
 }
 
 impl<M, T> Clone for SignalField<M, T>
@@ -102,11 +298,11 @@ where
     type Value = T;
 
     fn get_untracked(&self) -> Self::Value {
-        self.value.get_untracked()
+        self.value.with_untracked(T::clone)
     }
 
     fn try_get_untracked(&self) -> Option<Self::Value> {
-        self.value.try_get_untracked()
+        self.value.try_with_untracked(T::clone)
     }
 }
 
@@ -118,10 +314,23 @@ where
     type Value = T;
 
     fn get(&self) -> Self::Value {
-        self.value.get()
+        self.value.with(T::clone)
     }
 
     fn try_get(&self) -> Option<Self::Value> {
-        self.value.try_get()
+        self.value.try_with(T::clone)
+    }
+}
+
+impl<M, T> From<SignalField<M, T>> for MaybeSignal<T>
+where
+    T: Clone + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn from(field: SignalField<M, T>) -> Self {
+        match field.value {
+            SignalValue::Static(value) => MaybeSignal::Static(value.get_value()),
+            SignalValue::Dynamic(value) => MaybeSignal::Dynamic(value),
+        }
     }
 }
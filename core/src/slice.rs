@@ -0,0 +1,582 @@
+use crate::any::AnyField;
+use crate::field::Field;
+use crate::form::FormState;
+use crate::meta::FieldMeta;
+use crate::state::FieldState;
+use crate::widget::Widget;
+use leptos::html::ElementDescriptor;
+use leptos::*;
+use std::any::Any;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+///
+/// 保存整个结构体的信号
+///
+/// 结构体只保存一份,字段通过[`SliceField`]读写,写入字段时只通知该字段和结构体的订阅者。
+///
+pub struct SliceRoot<S: 'static> {
+    value: RootValue<S>,
+    trigger: Trigger,
+}
+
+///
+/// 结构体的存储位置
+///
+enum RootValue<S: 'static> {
+    ///
+    /// 控制器自己保存
+    ///
+    Stored(StoredValue<S>),
+
+    ///
+    /// 绑定的外部信号
+    ///
+    Bound(RwSignal<S>, BoundSource),
+}
+
+///
+/// 外部信号的变更通知
+///
+/// 字段不直接订阅外部信号,而是各自比较切片的新旧值,只在外部修改了该字段时通知,
+/// 避免字段的监听回调中写入字段时重复触发自身。
+///
+#[derive(Clone, Copy)]
+struct BoundSource {
+    changed: Trigger,
+    writing: StoredValue<bool>,
+}
+
+impl<S: 'static> SliceRoot<S> {
+    pub fn new(value: S) -> Self {
+        Self {
+            value: RootValue::Stored(store_value(value)),
+            trigger: create_trigger(),
+        }
+    }
+
+    ///
+    /// 绑定外部信号,字段的读写直接作用于该信号,外部修改信号时值变化的字段同样会收到通知
+    ///
+    /// 字段通过比较新旧值判断是否变化,字段类型没有实现`PartialEq`时每次修改都会通知。
+    ///
+    pub fn bind(source: RwSignal<S>) -> Self {
+        let trigger = create_trigger();
+        let bound = BoundSource {
+            changed: create_trigger(),
+            writing: store_value(false),
+        };
+        create_isomorphic_effect(move |initialized: Option<()>| {
+            source.track();
+            if initialized.is_some() && !bound.writing.get_value() {
+                bound.changed.notify();
+                trigger.notify();
+            }
+        });
+        Self {
+            value: RootValue::Bound(source, bound),
+            trigger,
+        }
+    }
+
+    ///
+    /// 创建结构体某个字段的只读信号,只在外部信号修改时通知
+    ///
+    pub fn slice<T: Clone + 'static>(&self, get: fn(&S) -> &T) -> Signal<T> {
+        let root = *self;
+        Signal::derive(move || {
+            root.track_source();
+            root.with_untracked(|root| get(root).clone())
+        })
+    }
+
+    fn track_source(&self) {
+        if let RootValue::Bound(_, bound) = self.value {
+            bound.changed.track();
+        }
+    }
+
+    fn try_update<O>(&self, f: impl FnOnce(&mut S) -> O) -> Option<O> {
+        match self.value {
+            RootValue::Stored(value) => value.try_update_value(f),
+            RootValue::Bound(source, bound) => {
+                bound.writing.try_update_value(|writing| *writing = true)?;
+                let result = source.try_update(f);
+                bound.writing.set_value(false);
+                result
+            }
+        }
+    }
+}
+
+impl<S: 'static> Clone for RootValue<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: 'static> Copy for RootValue<S> {}
+
+impl<S: 'static> SignalWithUntracked for SliceRoot<S> {
+    type Value = S;
+
+    fn with_untracked<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> O {
+        match self.value {
+            RootValue::Stored(value) => value.with_value(f),
+            RootValue::Bound(source, _) => source.with_untracked(f),
+        }
+    }
+
+    fn try_with_untracked<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> Option<O> {
+        match self.value {
+            RootValue::Stored(value) => value.try_with_value(f),
+            RootValue::Bound(source, bound) => {
+                // 控制器销毁后不再读取外部信号
+                bound.writing.try_with_value(|_| ())?;
+                source.try_with_untracked(f)
+            }
+        }
+    }
+}
+impl<S: 'static> SignalWith for SliceRoot<S> {
+    type Value = S;
+
+    fn with<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> O {
+        self.trigger.track();
+        self.with_untracked(f)
+    }
+
+    fn try_with<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> Option<O> {
+        self.trigger.try_track();
+        self.try_with_untracked(f)
+    }
+}
+impl<S: Clone + 'static> SignalGetUntracked for SliceRoot<S> {
+    type Value = S;
+
+    fn get_untracked(&self) -> Self::Value {
+        self.with_untracked(S::clone)
+    }
+
+    fn try_get_untracked(&self) -> Option<Self::Value> {
+        self.try_with_untracked(S::clone)
+    }
+}
+impl<S: Clone + 'static> SignalGet for SliceRoot<S> {
+    type Value = S;
+
+    fn get(&self) -> Self::Value {
+        self.with(S::clone)
+    }
+
+    fn try_get(&self) -> Option<Self::Value> {
+        self.try_with(S::clone)
+    }
+}
+impl<S: 'static> Clone for SliceRoot<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<S: 'static> Copy for SliceRoot<S> {}
+
+///
+/// 结构体的字段切片
+///
+/// 字段的值保存在[`SliceRoot`]中,每个字段拥有独立的触发器,
+/// 写入时只通知该字段和结构体的订阅者,不会通知其它字段。
+///
+pub struct SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    root: SliceRoot<S>,
+    trigger: Trigger,
+    get: fn(&S) -> &T,
+    get_mut: fn(&mut S) -> &mut T,
+    state: FieldState,
+    _mark: PhantomData<M>,
+}
+
+impl<M, S, T> SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    ///
+    /// 创建字段切片
+    ///
+    /// # Arguments
+    ///
+    /// * `root`: 保存结构体的信号
+    /// * `get`: 获取字段的引用
+    /// * `get_mut`: 获取字段的可变引用
+    /// * `form`: 控制器的表单状态
+    ///
+    pub fn new(
+        root: SliceRoot<S>,
+        get: fn(&S) -> &T,
+        get_mut: fn(&mut S) -> &mut T,
+        form: FormState,
+    ) -> Self {
+        let trigger = create_trigger();
+        if let RootValue::Bound(source, bound) = root.value {
+            // 外部修改信号时只通知值变化的字段,同步期间不执行值变更回调和依赖变更的处理
+            _ = watch(
+                move || source.with(|source| get(source).clone()),
+                move |new, old, _| {
+                    if !bound.writing.get_value() && old.is_none_or(|old| !M::EQ(old, new)) {
+                        form.sync(|| trigger.notify());
+                    }
+                },
+                false,
+            );
+        }
+        Self {
+            root,
+            trigger,
+            get,
+            get_mut,
+            state: FieldState::new(Some(form), M::MODE),
+            _mark: PhantomData,
+        }
+    }
+
+    ///
+    /// 字段所在的结构体信号
+    ///
+    pub fn root(&self) -> SliceRoot<S> {
+        self.root
+    }
+
+    ///
+    /// 字段状态
+    ///
+    pub fn state(&self) -> FieldState {
+        self.state
+    }
+
+    ///
+    /// 创建字段关联元素的引用,控制器的`focus_first_error`会聚焦到该元素
+    ///
+    pub fn node_ref<E>(&self) -> NodeRef<E>
+    where
+        E: ElementDescriptor + Clone + 'static,
+    {
+        self.state.node_ref()
+    }
+
+    ///
+    /// 监听字段值的变更,初始化时不会执行,返回停止监听的函数
+    ///
+    /// # Arguments
+    ///
+    /// * `f`: 变更回调,参数分别为新值和旧值
+    ///
+    pub fn watch(&self, f: impl Fn(&T, Option<&T>) + 'static) -> impl Fn() + Clone {
+        self.watch_with(f, false)
+    }
+
+    ///
+    /// 监听字段值的变更,初始化时立即执行一次,返回停止监听的函数
+    ///
+    pub fn watch_immediate(&self, f: impl Fn(&T, Option<&T>) + 'static) -> impl Fn() + Clone {
+        self.watch_with(f, true)
+    }
+
+    fn watch_with(
+        &self,
+        f: impl Fn(&T, Option<&T>) + 'static,
+        immediate: bool,
+    ) -> impl Fn() + Clone {
+        let (root, trigger, get) = (self.root, self.trigger, self.get);
+        let f = Rc::new(f);
+        watch(
+            move || {
+                trigger.track();
+                root.with_untracked(|root| get(root).clone())
+            },
+            move |new, old, _| f(new, old),
+            immediate,
+        )
+    }
+
+    ///
+    /// 是否已经失去过焦点(追踪)
+    ///
+    pub fn touched(&self) -> bool {
+        self.state.touched()
+    }
+
+    ///
+    /// 值是否已经变更(追踪)
+    ///
+    pub fn is_dirty(&self) -> bool {
+        self.state.is_dirty()
+    }
+}
+
+impl<M, S, T> Field for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn label(&self) -> &'static str {
+        M::LABEL
+    }
+
+    fn required(&self) -> bool {
+        M::REQUIRED
+    }
+
+    fn validate(&self) -> Option<Cow<'static, str>> {
+        self.with_untracked(M::VALIDATE)
+    }
+
+    fn set_default(&self) {
+        self.set(Default::default());
+        self.state.reset();
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        self.state.error(|| self.with(M::VALIDATE))
+    }
+
+    fn touch(&self) {
+        self.state.touch(self.with_untracked(M::VALIDATE));
+    }
+
+    fn revalidate(&self) {
+        self.state.revalidate(self.with_untracked(M::VALIDATE));
+    }
+
+    fn focus(&self) -> bool {
+        self.state.focus()
+    }
+
+    fn track(&self) {
+        self.trigger.track();
+    }
+
+    fn placeholder(&self) -> Option<&'static str> {
+        M::PLACEHOLDER
+    }
+
+    fn help(&self) -> Option<&'static str> {
+        M::HELP
+    }
+
+    fn hidden(&self) -> bool {
+        M::HIDDEN
+    }
+
+    fn order(&self) -> i32 {
+        M::ORDER
+    }
+
+    fn attrs(&self) -> &'static [(&'static str, &'static str)] {
+        M::ATTRS
+    }
+}
+
+impl<M, S, T> AnyField for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn is_dirty(&self) -> bool {
+        self.state.is_dirty()
+    }
+
+    fn touched(&self) -> bool {
+        self.state.touched()
+    }
+
+    fn value_string(&self) -> Option<String> {
+        self.with(M::DISPLAY)
+    }
+
+    fn set_value_string(&self, value: &str) -> Result<(), Cow<'static, str>> {
+        self.set(M::PARSE(value)?);
+        Ok(())
+    }
+
+    fn widget(&self) -> Widget {
+        M::WIDGET()
+    }
+
+    fn options(&self) -> Vec<String> {
+        M::OPTIONS()
+    }
+
+    fn field_state(&self) -> Option<FieldState> {
+        Some(self.state)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<M, S, T> SignalWithUntracked for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn with_untracked<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> O {
+        let get = self.get;
+        self.root.with_untracked(|root| f(get(root)))
+    }
+
+    fn try_with_untracked<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> Option<O> {
+        let get = self.get;
+        self.root.try_with_untracked(|root| f(get(root)))
+    }
+}
+impl<M, S, T> SignalWith for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn with<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> O {
+        Field::track(self);
+        self.with_untracked(f)
+    }
+
+    fn try_with<O>(&self, f: impl FnOnce(&Self::Value) -> O) -> Option<O> {
+        self.trigger.try_track();
+        self.try_with_untracked(f)
+    }
+}
+impl<M, S, T> SignalUpdateUntracked<T> for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn update_untracked(&self, f: impl FnOnce(&mut T)) {
+        self.try_update_untracked(f);
+    }
+
+    fn try_update_untracked<O>(&self, f: impl FnOnce(&mut T) -> O) -> Option<O> {
+        let get_mut = self.get_mut;
+        self.root.try_update(|root| f(get_mut(root)))
+    }
+}
+impl<M, S, T> SignalUpdate for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn update(&self, f: impl FnOnce(&mut Self::Value)) {
+        self.try_update(f);
+    }
+
+    fn try_update<O>(&self, f: impl FnOnce(&mut Self::Value) -> O) -> Option<O> {
+        let result = self.try_update_untracked(f);
+        if result.is_some() {
+            self.trigger.notify();
+            self.root.trigger.notify();
+            self.state.mark_dirty();
+        }
+        result
+    }
+}
+impl<M, S, T> SignalSetUntracked<T> for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn set_untracked(&self, new_value: T) {
+        self.update_untracked(|value| *value = new_value);
+    }
+
+    fn try_set_untracked(&self, new_value: T) -> Option<T> {
+        let mut new_value = Some(new_value);
+        self.try_update_untracked(|value| *value = new_value.take().unwrap());
+        new_value
+    }
+}
+impl<M, S, T> SignalSet for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn set(&self, new_value: Self::Value) {
+        self.update(|value| *value = new_value);
+    }
+
+    fn try_set(&self, new_value: Self::Value) -> Option<Self::Value> {
+        let mut new_value = Some(new_value);
+        self.try_update(|value| *value = new_value.take().unwrap());
+        new_value
+    }
+}
+impl<M, S, T> SignalGetUntracked for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn get_untracked(&self) -> Self::Value {
+        self.with_untracked(T::clone)
+    }
+
+    fn try_get_untracked(&self) -> Option<Self::Value> {
+        self.try_with_untracked(T::clone)
+    }
+}
+impl<M, S, T> SignalGet for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn get(&self) -> Self::Value {
+        self.with(T::clone)
+    }
+
+    fn try_get(&self) -> Option<Self::Value> {
+        self.try_with(T::clone)
+    }
+}
+impl<M, S, T> Clone for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<M, S, T> Copy for SliceField<M, S, T>
+where
+    S: 'static,
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+}
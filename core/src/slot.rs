@@ -0,0 +1,45 @@
+use leptos::*;
+
+///
+/// 自定义指定字段的渲染
+///
+#[slot]
+pub struct FieldSlot {
+    /// 字段名称
+    name: &'static str,
+
+    children: ChildrenFn,
+}
+
+impl FieldSlot {
+    ///
+    /// 字段名称
+    ///
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    ///
+    /// 渲染自定义的内容
+    ///
+    pub fn render(&self) -> View {
+        (self.children)().into_view()
+    }
+}
+
+///
+/// 自定义表单底部的渲染,替换默认的提交栏
+///
+#[slot]
+pub struct FooterSlot {
+    children: ChildrenFn,
+}
+
+impl FooterSlot {
+    ///
+    /// 渲染自定义的内容
+    ///
+    pub fn render(&self) -> View {
+        (self.children)().into_view()
+    }
+}
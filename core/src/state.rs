@@ -0,0 +1,301 @@
+use crate::form::FormState;
+use crate::mode::ValidationMode;
+use leptos::html::ElementDescriptor;
+use leptos::*;
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::rc::Rc;
+
+///
+/// 字段的交互状态,用于根据校验模式决定是否展示校验错误信息
+///
+/// 状态的信号在第一次读取或者修改时才会创建,创建在字段的所有者中,
+/// 没有使用状态的字段不会创建额外的响应式节点。
+///
+#[derive(Clone, Copy)]
+pub struct FieldState {
+    form: Option<FormState>,
+    mode: ValidationMode,
+    owner: Option<Owner>,
+    inner: StoredValue<StateInner>,
+}
+
+///
+/// 不需要追踪的状态
+///
+#[derive(Default)]
+struct StateInner {
+    ///
+    /// 状态的信号,第一次使用时创建
+    ///
+    signals: Option<StateSignals>,
+
+    ///
+    /// 信号创建前值是否已经变更
+    ///
+    dirty: bool,
+
+    ///
+    /// 是否已经监听字段的值
+    ///
+    watching: bool,
+
+    ///
+    /// 聚焦到字段关联的元素
+    ///
+    focus: Option<Rc<dyn Fn() -> bool>>,
+
+    ///
+    /// 缓存的值,按照值的类型和键区分
+    ///
+    cache: Vec<(TypeId, &'static str, Box<dyn Any>)>,
+}
+
+#[derive(Clone, Copy)]
+struct StateSignals {
+    touched: RwSignal<bool>,
+    dirty: RwSignal<bool>,
+    validated: RwSignal<bool>,
+    blurred: RwSignal<Option<Cow<'static, str>>>,
+}
+
+impl FieldState {
+    ///
+    /// 创建字段状态
+    ///
+    /// # Arguments
+    ///
+    /// * `form`: 表单状态,单独创建的字段为空,不会被标记为已提交
+    /// * `mode`: 字段单独配置的校验模式,为空时使用表单的校验模式
+    ///
+    pub fn new(form: Option<FormState>, mode: Option<ValidationMode>) -> Self {
+        Self {
+            form,
+            mode: mode.or(form.map(|form| form.mode())).unwrap_or_default(),
+            owner: Owner::current(),
+            inner: store_value(StateInner::default()),
+        }
+    }
+
+    pub fn form(&self) -> Option<FormState> {
+        self.form
+    }
+
+    pub fn mode(&self) -> ValidationMode {
+        self.mode
+    }
+
+    ///
+    /// 获取已经创建的信号
+    ///
+    fn created(&self) -> Option<StateSignals> {
+        self.inner.with_value(|inner| inner.signals)
+    }
+
+    ///
+    /// 获取状态的信号,不存在时在字段的所有者中创建
+    ///
+    fn signals(&self) -> StateSignals {
+        if let Some(signals) = self.created() {
+            return signals;
+        }
+        let dirty = self.inner.with_value(|inner| inner.dirty);
+        let signals = self.run_in_owner(move || StateSignals {
+            touched: create_rw_signal(false),
+            dirty: create_rw_signal(dirty),
+            validated: create_rw_signal(false),
+            blurred: create_rw_signal(None),
+        });
+        self.inner
+            .update_value(|inner| inner.signals = Some(signals));
+        signals
+    }
+
+    fn run_in_owner<O>(&self, f: impl FnOnce() -> O) -> O {
+        match self.owner {
+            Some(owner) => with_owner(owner, f),
+            None => f(),
+        }
+    }
+
+    ///
+    /// 是否已经失去过焦点
+    ///
+    pub fn touched(&self) -> bool {
+        self.signals().touched.get()
+    }
+
+    ///
+    /// 值是否已经变更
+    ///
+    pub fn is_dirty(&self) -> bool {
+        self.signals().dirty.get()
+    }
+
+    ///
+    /// 标记值已经变更,信号还没有创建时只记录标记
+    ///
+    pub fn mark_dirty(&self) {
+        match self.created() {
+            Some(signals) => {
+                if !signals.dirty.get_untracked() {
+                    signals.dirty.set(true);
+                }
+            }
+            None => self.inner.update_value(|inner| inner.dirty = true),
+        }
+    }
+
+    ///
+    /// 监听字段的值,变更时标记值已经变更,重复调用只会监听一次
+    ///
+    /// 字段的信号直接交给组件写入时使用,通过字段写入的值不需要监听。
+    ///
+    /// # Arguments
+    ///
+    /// * `track`: 追踪字段的值
+    ///
+    pub fn watch_dirty(&self, track: impl Fn() + 'static) {
+        if self.inner.with_value(|inner| inner.watching) {
+            return;
+        }
+        self.inner.update_value(|inner| inner.watching = true);
+        let state = *self;
+        self.run_in_owner(move || {
+            _ = watch(track, move |_, _, _| state.mark_dirty(), false);
+        });
+    }
+
+    ///
+    /// 标记字段失去焦点,并记录当前的校验结果
+    ///
+    pub fn touch(&self, error: Option<Cow<'static, str>>) {
+        let signals = self.signals();
+        if !signals.touched.get_untracked() {
+            signals.touched.set(true);
+        }
+        signals.blurred.set(error);
+    }
+
+    ///
+    /// 重新校验并记录校验结果,除提交时校验外的模式都会展示校验错误信息
+    ///
+    pub fn revalidate(&self, error: Option<Cow<'static, str>>) {
+        let signals = self.signals();
+        if !signals.validated.get_untracked() {
+            signals.validated.set(true);
+        }
+        signals.blurred.set(error);
+    }
+
+    ///
+    /// 创建字段关联元素的引用,用于聚焦到字段
+    ///
+    pub fn node_ref<E>(&self) -> NodeRef<E>
+    where
+        E: ElementDescriptor + Clone + 'static,
+    {
+        let node_ref = create_node_ref::<E>();
+        let focus = move || match node_ref.get_untracked() {
+            Some(element) => {
+                let element = element.into_any();
+                _ = element.focus();
+                element.scroll_into_view();
+                true
+            }
+            None => false,
+        };
+        self.inner
+            .update_value(|inner| inner.focus = Some(Rc::new(focus)));
+        node_ref
+    }
+
+    ///
+    /// 聚焦到字段关联的元素,返回是否聚焦成功
+    ///
+    pub fn focus(&self) -> bool {
+        self.inner
+            .with_value(|inner| inner.focus.clone())
+            .is_some_and(|focus| focus())
+    }
+
+    ///
+    /// 获取缓存的值,不存在时在字段的所有者中创建
+    ///
+    /// 字段重复转换为组件的`Model`等需要创建响应式节点的值时复用第一次创建的值,
+    /// 组件重新渲染时不会重复创建信号和副作用。
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: 区分相同类型的不同缓存
+    /// * `create`: 创建缓存的值
+    ///
+    pub fn cached<V: Copy + 'static>(&self, key: &'static str, create: impl FnOnce() -> V) -> V {
+        let id = TypeId::of::<V>();
+        let cached = self.inner.with_value(|inner| {
+            inner
+                .cache
+                .iter()
+                .find(|(type_id, name, _)| *type_id == id && *name == key)
+                .and_then(|(_, _, value)| value.downcast_ref::<V>().copied())
+        });
+        if let Some(value) = cached {
+            return value;
+        }
+        let value = self.run_in_owner(create);
+        self.inner
+            .update_value(|inner| inner.cache.push((id, key, Box::new(value))));
+        value
+    }
+
+    ///
+    /// 重置字段状态
+    ///
+    pub fn reset(&self) {
+        match self.created() {
+            Some(signals) => {
+                signals.touched.set(false);
+                signals.dirty.set(false);
+                signals.validated.set(false);
+                signals.blurred.set(None);
+            }
+            None => self.inner.update_value(|inner| inner.dirty = false),
+        }
+    }
+
+    ///
+    /// 根据校验模式获取需要展示的校验错误信息
+    ///
+    /// # Arguments
+    ///
+    /// * `validate`: 校验当前值
+    ///
+    pub fn error(
+        &self,
+        validate: impl FnOnce() -> Option<Cow<'static, str>>,
+    ) -> Option<Cow<'static, str>> {
+        if self.form.is_some_and(|form| form.submitted()) {
+            return validate();
+        }
+        match self.mode {
+            ValidationMode::OnChange => {
+                let signals = self.signals();
+                if signals.dirty.get() || signals.validated.get() {
+                    validate()
+                } else {
+                    None
+                }
+            }
+            ValidationMode::OnTouched => {
+                let signals = self.signals();
+                if signals.touched.get() || signals.validated.get() {
+                    validate()
+                } else {
+                    None
+                }
+            }
+            ValidationMode::OnBlur => self.signals().blurred.get(),
+            ValidationMode::OnSubmit => None,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::any::AnyField;
+use crate::form::FormState;
+
+///
+/// 字段存储
+///
+/// 控制器通过该特征创建字段以及获取字段的值,自定义字段实现该特征后
+/// 可以通过`#[field(with = "...")]`替换默认的`RwSignalField`和`SignalField`。
+/// 自定义字段的泛型参数需要和内置字段保持一致:`<M: FieldMeta<Type = T>, T>`。
+/// 自定义字段同时需要实现[`AnyField`],以便通过字段枚举或者名称统一访问,
+/// 其中[`Field::track`](crate::Field::track)用于监听字段的变更,需要追踪字段的值,
+/// 使用默认实现时`on_change`、`depends_on`和`watch_any`不会收到该字段的变更。
+///
+pub trait FieldStorage<T>: AnyField + Copy + 'static {
+    ///
+    /// 使用初始值创建字段
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: 初始值
+    /// * `form`: 控制器的表单状态
+    ///
+    fn create(value: T, form: FormState) -> Self;
+
+    ///
+    /// 获取字段当前的值(不追踪)
+    ///
+    fn snapshot(&self) -> T;
+}
@@ -0,0 +1,105 @@
+use crate::aria::FieldIds;
+use crate::field::Field;
+use leptos::*;
+use thaw::{use_theme, Theme};
+
+///
+/// 字段是否存在需要展示的错误信息,用于设置thaw输入组件的`invalid`属性
+///
+pub fn field_invalid<F>(field: F) -> MaybeSignal<bool>
+where
+    F: Field + Copy + 'static,
+{
+    MaybeSignal::derive(move || field.error().is_some())
+}
+
+///
+/// 字段标签
+///
+/// 展示字段的名称,必填字段会显示星号,子组件失去焦点时标记字段已经失去焦点,并在下方展示帮助信息和错误信息。
+/// 指定`ids`时子组件通过`aria-labelledby`和`aria-describedby`关联标签、帮助信息和错误信息,
+/// 并且子组件的输入元素使用`ids.input()`作为id时通过`<label for>`关联标签,输入组件不支持设置id时`labelable`为`false`。
+///
+#[component]
+pub fn FieldLabel<F>(
+    field: F,
+    #[prop(optional)] ids: Option<FieldIds>,
+    #[prop(default = true)] labelable: bool,
+    children: Children,
+) -> impl IntoView
+where
+    F: Field + Copy + 'static,
+{
+    let theme = use_theme(Theme::light);
+    let required_style =
+        move || theme.with(|theme| format!("color: {};", theme.common.color_error));
+    let help = field.help();
+    let help_style =
+        move || theme.with(|theme| format!("font-size: {};", theme.common.font_size_small));
+    let describedby = {
+        let ids = ids.clone();
+        move || {
+            ids.as_ref()
+                .and_then(|ids| ids.described_by(help.is_some(), field.error().is_some()))
+        }
+    };
+    let label_id = ids.as_ref().map(FieldIds::label);
+    view! {
+        <div
+            class="leptos-controls-field"
+            role="group"
+            aria-labelledby=label_id.clone()
+            aria-describedby=describedby
+            on:focusout=move |_| field.touch()
+        >
+            <label
+                class="leptos-controls-field__label"
+                id=label_id
+                for=ids.as_ref().filter(|_| labelable).map(FieldIds::input)
+            >
+                {field.label()}
+                {field
+                    .required()
+                    .then(|| view! { <span style=required_style>" *"</span> })}
+            </label>
+            {children()}
+            {help
+                .map(|help| {
+                    view! {
+                        <div
+                            class="leptos-controls-field__help"
+                            id=ids.as_ref().map(FieldIds::help)
+                            style=help_style
+                        >
+                            {help}
+                        </div>
+                    }
+                })}
+            <FieldError field id=ids.as_ref().map(FieldIds::error)/>
+        </div>
+    }
+}
+
+///
+/// 字段的错误信息,根据校验模式展示,错误信息变更时通知辅助技术
+///
+#[component]
+pub fn FieldError<F>(field: F, #[prop(optional, into)] id: MaybeProp<String>) -> impl IntoView
+where
+    F: Field + Copy + 'static,
+{
+    let theme = use_theme(Theme::light);
+    let style = move || {
+        theme.with(|theme| {
+            format!(
+                "color: {}; font-size: {};",
+                theme.common.color_error, theme.common.font_size_small
+            )
+        })
+    };
+    view! {
+        <div class="leptos-controls-field__error" id=move || id.get() role="alert" aria-live="polite" style=style>
+            {move || field.error()}
+        </div>
+    }
+}
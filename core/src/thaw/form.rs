@@ -0,0 +1,68 @@
+use crate::controls::Controls;
+use leptos::*;
+use thaw::{Button, ButtonColor, ButtonVariant, Space, SpaceJustify};
+
+///
+/// 表单容器
+///
+/// 为子组件提供控制器和表单状态的上下文,并垂直排列子组件。
+///
+#[component]
+pub fn FormControls<C>(controls: C, children: Children) -> impl IntoView
+where
+    C: Controls,
+{
+    provide_context(controls);
+    controls.form().provide();
+    view! {
+        <Space vertical=true>
+            {children()}
+        </Space>
+    }
+}
+
+///
+/// 表单的提交栏
+///
+/// 点击提交时校验所有字段,校验成功后调用`on_submit`,失败时聚焦到第一个校验失败的字段,
+/// 没有设置`on_cancel`时不展示取消按钮。
+///
+#[component]
+pub fn SubmitBar<C>(
+    controls: C,
+    #[prop(into)] on_submit: Callback<C::Value>,
+    #[prop(optional, into)] on_cancel: Option<Callback<()>>,
+    #[prop(optional, into)] loading: MaybeSignal<bool>,
+    #[prop(optional, into)] submit_label: Option<String>,
+    #[prop(optional, into)] cancel_label: Option<String>,
+) -> impl IntoView
+where
+    C: Controls,
+    C::Value: 'static,
+{
+    let submit_label = submit_label.unwrap_or_else(|| String::from("提交"));
+    let cancel_label = cancel_label.unwrap_or_else(|| String::from("取消"));
+    let submit = move |_| {
+        if let Ok(value) = controls.submit() {
+            on_submit.call(value);
+        }
+    };
+    view! {
+        <Space justify=SpaceJustify::End>
+            <Button color=ButtonColor::Primary loading on_click=submit>
+                {submit_label}
+            </Button>
+            {on_cancel
+                .map(|on_cancel| {
+                    view! {
+                        <Button
+                            variant=ButtonVariant::Outlined
+                            on_click=move |_| on_cancel.call(())
+                        >
+                            {cancel_label}
+                        </Button>
+                    }
+                })}
+        </Space>
+    }
+}
@@ -0,0 +1,90 @@
+//!
+//! thaw输入组件的选择
+//!
+//! `thaw_form`生成的表单在编译时无法确定类型是否实现了[`SelectOptions`],
+//! 和[`crate::convert`]相同通过自动引用的方法查找规则选择下拉选择框或者文本输入框。
+//!
+
+use crate::thaw::select_options;
+use crate::SelectOptions;
+use leptos::*;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use thaw::Select;
+use thaw_utils::Model;
+
+///
+/// 数字输入框的步长
+///
+/// `widget = "number"`的字段使用thaw的`InputNumber`渲染,点击增减按钮时按照步长修改,
+/// 已经为所有基本数字类型实现,自定义的数字类型实现该特征后同样可以使用。
+///
+#[diagnostic::on_unimplemented(
+    message = "`widget = \"number\"` requires a numeric type, `{Self}` does not implement `NumberStep`",
+    label = "rendered by the thaw `InputNumber`",
+    note = "implement `NumberStep` for custom numeric types, or render the field with a `FieldSlot`"
+)]
+pub trait NumberStep {
+    ///
+    /// 每次增减的值
+    ///
+    fn step() -> Self;
+}
+
+macro_rules! impl_number_step {
+    ($($ty:ty => $step:expr),* $(,)?) => {
+        $(
+            impl NumberStep for $ty {
+                fn step() -> Self {
+                    $step
+                }
+            }
+        )*
+    };
+}
+
+impl_number_step! {
+    i8 => 1, i16 => 1, i32 => 1, i64 => 1, i128 => 1, isize => 1,
+    u8 => 1, u16 => 1, u32 => 1, u64 => 1, u128 => 1, usize => 1,
+    f32 => 1.0, f64 => 1.0,
+}
+
+pub struct SelectInput<F, T>(pub F, pub PhantomData<T>);
+
+pub trait ViaSelectInput {
+    fn is_select(&self) -> bool;
+
+    fn select_input(&self, text: impl FnOnce() -> View) -> View;
+}
+
+impl<F, T> ViaSelectInput for SelectInput<F, T>
+where
+    F: Into<Model<Option<T>>> + Copy,
+    T: SelectOptions + Display + Eq + Hash + Clone + 'static,
+{
+    fn is_select(&self) -> bool {
+        true
+    }
+
+    fn select_input(&self, _: impl FnOnce() -> View) -> View {
+        let (value, options) = (self.0, select_options::<T>());
+        view! { <Select value options /> }.into_view()
+    }
+}
+
+pub trait NoSelectInput {
+    fn is_select(&self) -> bool;
+
+    fn select_input(&self, text: impl FnOnce() -> View) -> View;
+}
+
+impl<F, T> NoSelectInput for &SelectInput<F, T> {
+    fn is_select(&self) -> bool {
+        false
+    }
+
+    fn select_input(&self, text: impl FnOnce() -> View) -> View {
+        text()
+    }
+}
@@ -0,0 +1,9 @@
+mod field;
+mod form;
+mod input;
+mod model;
+
+pub use field::{field_invalid, FieldError, FieldLabel};
+pub use form::{FormControls, SubmitBar};
+pub use input::{NoSelectInput, NumberStep, SelectInput, ViaSelectInput};
+pub use model::{select_options, string_model};
@@ -0,0 +1,160 @@
+use crate::{
+    AnyField, FieldMeta, FieldState, RwSignalField, SelectOptions, SignalField, SliceField,
+};
+use leptos::*;
+use std::fmt::Display;
+use thaw::SelectOption;
+use thaw_utils::{Model, OptionalProp};
+
+impl<M: FieldMeta<Type = T>, T: Clone + Default + 'static> From<RwSignalField<M, T>> for Model<T> {
+    fn from(field: RwSignalField<M, T>) -> Self {
+        field.signal().into()
+    }
+}
+
+///
+/// 创建读写分离的`Model`,组件写入的值通过`write`写回字段,组件读取的值来自`read`
+///
+/// 每次创建都会生成新的信号和副作用,字段转换时通过[`FieldState::cached`]只创建一次。
+///
+fn map_model<T: Clone + 'static>(
+    read: impl Fn() -> T + 'static,
+    write: impl Fn(T) + 'static,
+) -> Model<T> {
+    let (input, set_input) = create_signal(untrack(&read));
+    create_isomorphic_effect(move |prev: Option<()>| {
+        let value = input.get();
+        if prev.is_some() {
+            untrack(|| write(value));
+        }
+    });
+    (Signal::derive(read), set_input).into()
+}
+
+///
+/// 可选字符串字段的`Model`,空字符串写回为`None`
+///
+fn optional_string_model<F>(field: F, state: FieldState) -> Model<String>
+where
+    F: SignalGet<Value = Option<String>> + SignalSet<Value = Option<String>> + Copy + 'static,
+{
+    state.cached("optional_string", move || {
+        map_model(
+            move || field.get().unwrap_or_default(),
+            move |value: String| field.set(Some(value).filter(|value| !value.is_empty())),
+        )
+    })
+}
+
+///
+/// 下拉选择框的`Model`,清空选择时写回默认值
+///
+fn select_model<F, T>(field: F, state: FieldState) -> Model<Option<T>>
+where
+    F: SignalGet<Value = T> + SignalSet<Value = T> + Copy + 'static,
+    T: Clone + Default + 'static,
+{
+    state.cached("select", move || {
+        map_model(
+            move || Some(field.get()),
+            move |value: Option<T>| field.set(value.unwrap_or_default()),
+        )
+    })
+}
+
+impl<M, T> From<RwSignalField<M, T>> for Model<Option<T>>
+where
+    M: FieldMeta<Type = T> + 'static,
+    T: Clone + Default + 'static,
+{
+    fn from(field: RwSignalField<M, T>) -> Self {
+        select_model(field, field.state())
+    }
+}
+
+impl<M> From<RwSignalField<M, Option<String>>> for Model<String>
+where
+    M: FieldMeta<Type = Option<String>> + 'static,
+{
+    fn from(field: RwSignalField<M, Option<String>>) -> Self {
+        optional_string_model(field, field.state())
+    }
+}
+
+impl<M, S, T> From<SliceField<M, S, T>> for Model<T>
+where
+    M: FieldMeta<Type = T> + 'static,
+    T: Clone + Default + 'static,
+{
+    fn from(field: SliceField<M, S, T>) -> Self {
+        field.state().cached("value", move || {
+            map_model(move || field.get(), move |value| field.set(value))
+        })
+    }
+}
+
+impl<M, S, T> From<SliceField<M, S, T>> for Model<Option<T>>
+where
+    M: FieldMeta<Type = T> + 'static,
+    T: Clone + Default + 'static,
+{
+    fn from(field: SliceField<M, S, T>) -> Self {
+        select_model(field, field.state())
+    }
+}
+
+impl<M, S> From<SliceField<M, S, Option<String>>> for Model<String>
+where
+    M: FieldMeta<Type = Option<String>> + 'static,
+{
+    fn from(field: SliceField<M, S, Option<String>>) -> Self {
+        optional_string_model(field, field.state())
+    }
+}
+
+impl<M, T> From<SignalField<M, T>> for OptionalProp<MaybeSignal<T>>
+where
+    M: FieldMeta<Type = T> + 'static,
+    T: Clone + 'static,
+{
+    fn from(field: SignalField<M, T>) -> Self {
+        OptionalProp::from(MaybeSignal::from(field))
+    }
+}
+
+///
+/// 字段的值转换为字符串的`Model`,读取时通过`Display`转换,写入时通过`FromStr`解析,
+/// 解析失败或者字段只读时写入的值会被忽略
+///
+/// 存在[`AnyField::field_state`]的字段缓存创建的`Model`,只读字段需要和组件的`disabled`属性一起使用。
+///
+pub fn string_model<F>(field: F) -> Model<String>
+where
+    F: AnyField + Copy + 'static,
+{
+    let create = move || {
+        map_model(
+            move || field.value_string().unwrap_or_default(),
+            move |value: String| {
+                let _ = field.set_value_string(&value);
+            },
+        )
+    };
+    match field.field_state() {
+        Some(state) => state.cached("string", create),
+        None => create(),
+    }
+}
+
+///
+/// 类型的所有选项转换为下拉选择框的选项,选项的显示文本通过`Display`获取
+///
+pub fn select_options<T>() -> Vec<SelectOption<T>>
+where
+    T: SelectOptions + Display,
+{
+    T::options()
+        .into_iter()
+        .map(|value| SelectOption::new(value.to_string(), value))
+        .collect()
+}
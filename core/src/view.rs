@@ -0,0 +1,219 @@
+use crate::aria::FieldIds;
+use crate::controls::Controls;
+use crate::slot::{FieldSlot, FooterSlot};
+use crate::widget::Widget;
+use leptos::*;
+
+///
+/// 根据控制器自动渲染表单
+///
+/// 每个字段渲染标签、输入控件和校验错误信息,输入控件根据字段的[`Widget`]选择,
+/// 可以通过[`FieldSlot`]替换指定字段的渲染,通过[`FooterSlot`]替换默认的提交按钮。
+/// 提交时校验所有字段,校验成功后调用`on_submit`,失败时聚焦到第一个校验失败的字段。
+/// 字段元素的id由`id`和字段名称组成,`id`默认为[`Controls::ID`]。
+///
+/// 字段类型不支持字符串转换时(没有实现`Display`和`FromStr`,`Option<String>`除外)输入控件会被禁用,
+/// 需要通过[`FieldSlot`]自定义渲染。
+///
+#[component]
+pub fn AutoForm<C>(
+    controls: C,
+    #[prop(optional)] field_slot: Vec<FieldSlot>,
+    #[prop(optional)] footer_slot: Option<FooterSlot>,
+    #[prop(optional, into)] on_submit: Option<Callback<C::Value>>,
+    #[prop(optional, into)] submit_label: Option<String>,
+    #[prop(optional, into)] id: Option<String>,
+) -> impl IntoView
+where
+    C: Controls,
+    C::Value: 'static,
+{
+    // 按照顺序渲染,隐藏的字段只渲染插槽
+    let mut names = C::FIELDS
+        .iter()
+        .copied()
+        .filter(|&name| {
+            field_slot.iter().any(|slot| slot.name() == name)
+                || controls
+                    .get_field(name)
+                    .is_some_and(|field| !field.hidden())
+        })
+        .collect::<Vec<_>>();
+    names.sort_by_key(|&name| controls.get_field(name).map(|field| field.order()));
+    let prefix = id.unwrap_or_else(|| String::from(C::ID));
+    let fields = names
+        .into_iter()
+        .map(
+            |name| match field_slot.iter().find(|slot| slot.name() == name) {
+                Some(slot) => slot.render(),
+                None => auto_field(controls, &prefix, name).into_view(),
+            },
+        )
+        .collect_view();
+    let submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        if let Ok(value) = controls.submit() {
+            if let Some(on_submit) = on_submit {
+                on_submit.call(value);
+            }
+        }
+    };
+    let footer = match footer_slot {
+        Some(footer) => footer.render(),
+        None => {
+            let submit_label = submit_label.unwrap_or_else(|| String::from("提交"));
+            view! { <button type="submit">{submit_label}</button> }.into_view()
+        }
+    };
+    view! {
+        <form class="auto-form" on:submit=submit>
+            {fields}
+            {footer}
+        </form>
+    }
+}
+
+///
+/// 渲染单个字段
+///
+fn auto_field<C: Controls>(controls: C, prefix: &str, name: &'static str) -> impl IntoView {
+    let field = controls
+        .get_field(name)
+        .expect("field in `Controls::FIELDS`");
+    let label = field.label();
+    let required = field.required();
+    // 不支持字符串转换的字段无法通过输入控件读写
+    let disabled = field.readonly() || untrack(|| field.value_string()).is_none();
+    let value = move || {
+        controls
+            .get_field(name)
+            .and_then(|field| field.value_string())
+            .unwrap_or_default()
+    };
+    let set_value = move |value: &str| {
+        if let Some(field) = controls.get_field(name) {
+            let _ = field.set_value_string(value);
+        }
+    };
+    let touch = move |_| {
+        if let Some(field) = controls.get_field(name) {
+            field.touch();
+        }
+    };
+    let error = move || controls.get_field(name).and_then(|field| field.error());
+    let placeholder = field.placeholder();
+    let help = field.help();
+    let ids = FieldIds::new(prefix, name);
+    let mut attrs = ids.aria_attrs(required, help.is_some(), move || error().is_some());
+    attrs.extend(
+        field
+            .attrs()
+            .iter()
+            .map(|&(name, value)| (name, value.into_attribute())),
+    );
+    let input = match field.widget() {
+        Widget::Text => view! {
+            <input
+                {..attrs}
+                type="text"
+                name=name
+                placeholder=placeholder
+                disabled=disabled
+                value=value
+                prop:value=value
+                on:input=move |ev| set_value(&event_target_value(&ev))
+                on:blur=touch
+            />
+        }
+        .into_view(),
+        Widget::Password => view! {
+            <input
+                {..attrs}
+                type="password"
+                name=name
+                placeholder=placeholder
+                disabled=disabled
+                prop:value=value
+                on:input=move |ev| set_value(&event_target_value(&ev))
+                on:blur=touch
+            />
+        }
+        .into_view(),
+        Widget::Number => view! {
+            <input
+                {..attrs}
+                type="number"
+                name=name
+                placeholder=placeholder
+                disabled=disabled
+                value=value
+                prop:value=value
+                on:input=move |ev| set_value(&event_target_value(&ev))
+                on:blur=touch
+            />
+        }
+        .into_view(),
+        Widget::Checkbox => view! {
+            <input
+                {..attrs}
+                type="checkbox"
+                name=name
+                disabled=disabled
+                checked=move || value() == "true"
+                prop:checked=move || value() == "true"
+                on:change=move |ev| set_value(&event_target_checked(&ev).to_string())
+                on:blur=touch
+            />
+        }
+        .into_view(),
+        Widget::Select => {
+            let options = field
+                .options()
+                .into_iter()
+                .map(|option| {
+                    let selected = {
+                        let option = option.clone();
+                        move || value() == option
+                    };
+                    view! { <option value=option.clone() selected=selected>{option}</option> }
+                })
+                .collect_view();
+            view! {
+                <select
+                    {..attrs}
+                    name=name
+                    disabled=disabled
+                    on:change=move |ev| set_value(&event_target_value(&ev))
+                    on:blur=touch
+                >
+                    {options}
+                </select>
+            }
+            .into_view()
+        }
+        Widget::TextArea => view! {
+            <textarea
+                {..attrs}
+                name=name
+                placeholder=placeholder
+                disabled=disabled
+                prop:value=value
+                on:input=move |ev| set_value(&event_target_value(&ev))
+                on:blur=touch
+            >
+                {untrack(value)}
+            </textarea>
+        }
+        .into_view(),
+    };
+    view! {
+        <div class="auto-form-field" class:required=required>
+            <label {..ids.label_attrs()}>{label}</label>
+            {input}
+            {help.map(|help| view! { <small class="auto-form-help" id=ids.help()>{help}</small> })}
+            <span class="auto-form-error" {..ids.error_attrs()}>
+                {error}
+            </span>
+        </div>
+    }
+}
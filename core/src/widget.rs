@@ -0,0 +1,48 @@
+///
+/// 字段的输入控件类型
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Widget {
+    ///
+    /// 文本输入框
+    ///
+    #[default]
+    Text,
+
+    ///
+    /// 密码输入框
+    ///
+    Password,
+
+    ///
+    /// 数字输入框
+    ///
+    Number,
+
+    ///
+    /// 复选框
+    ///
+    Checkbox,
+
+    ///
+    /// 下拉选择框,选项由字段类型的[`SelectOptions`]提供
+    ///
+    Select,
+
+    ///
+    /// 多行文本输入框
+    ///
+    TextArea,
+}
+
+///
+/// 下拉选择框的选项
+///
+/// 通常由枚举实现,选项的值和显示文本通过`Display`获取,选中时通过`FromStr`解析。
+///
+pub trait SelectOptions: Sized {
+    ///
+    /// 全部选项
+    ///
+    fn options() -> Vec<Self>;
+}
@@ -1,7 +1,8 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
 
-use crate::options::ControlOptions;
+use crate::options::{ControlOptions, DependencyAction};
 
 pub struct ControlStruct<'a> {
     options: &'a ControlOptions,
@@ -19,99 +20,327 @@ impl<'a> From<ControlStruct<'a>> for TokenStream {
         let ident = options.ident();
         let vis = options.vis();
         let control_struct_ident = options.control_struct_ident();
+        let mode = options.mode();
         let field_tokens = options.field_tokens();
+        let stored_field_tokens = options.stored_field_tokens();
+        let value_field_tokens = options
+            .control_fields()
+            .map(|field| field.ident())
+            .collect::<Vec<_>>();
+        let has_compute = options
+            .control_fields()
+            .any(|field| field.compute().is_some());
+        let all_field_tokens = options
+            .fields()
+            .iter()
+            .map(|field| field.ident())
+            .collect::<Vec<_>>();
 
         // 控制器字段
-        let field_with_type_tokens = options.fields().iter().map(|field| {
-            let ty = field.ty();
+        let field_with_type_tokens = options.control_fields().map(|field| {
             let vis = field.vis();
             let field_ident = field.ident();
-            let field_struct_ident = field.struct_ident(ident);
-            if field.readonly() {
-                quote! {
-                   #vis #field_ident: leptos_controls::SignalField<#field_struct_ident, #ty>
-                }
-            } else {
-                quote! {
-                    #vis #field_ident: leptos_controls::RwSignalField<#field_struct_ident, #ty>
-                }
+            let storage_ty = options.storage_ty(field);
+            quote! {
+                #vis #field_ident: #storage_ty
             }
         });
 
-        // 创建RwSignalField
-        let set_signal_tokens = options.fields().iter().map(|field| {
+        // 单个信号保存整个结构体
+        let root_field_type_tokens = if options.single() {
+            quote! { __root: leptos_controls::SliceRoot<#ident>, }
+        } else {
+            quote! {}
+        };
+
+        // 跳过字段保存原始值
+        let stored_field_with_type_tokens = options.stored_fields().map(|field| {
+            let ty = field.ty();
             let field_ident = field.ident();
-            if field.readonly() {
-                quote! {
-                    let #field_ident = leptos_controls::SignalField::new(#field_ident);
+            quote! {
+                #field_ident: leptos::StoredValue<#ty>
+            }
+        });
+
+        // 创建字段
+        let set_signal_tokens = options.control_fields().map(|field| {
+            let ty = field.ty();
+            let field_ident = field.ident();
+            let storage_ty = options.storage_ty(field);
+            match field.compute() {
+                Some(_) => {
+                    let compute_ident = format_ident!("__compute_{}", field_ident);
+                    // 控制器创建完成之前读取时返回初始值
+                    quote! {
+                        let #field_ident = <#storage_ty>::with_signal(
+                            leptos::Signal::derive(move || match __controls.get_value() {
+                                Some(controls) => #compute_ident(&controls),
+                                None => std::clone::Clone::clone(&#field_ident),
+                            }),
+                            __form,
+                        );
+                    }
                 }
-            } else {
+                None if options.is_slice(field) => quote! {
+                    let #field_ident = <#storage_ty>::new(
+                        __root,
+                        |value| &value.#field_ident,
+                        |value| &mut value.#field_ident,
+                        __form,
+                    );
+                },
+                None if field.readonly() => quote! {
+                    let #field_ident = match __sources.#field_ident {
+                        Some(source) => <#storage_ty>::with_signal(source, __form),
+                        None => <#storage_ty as leptos_controls::FieldStorage<#ty>>::create(#field_ident, __form),
+                    };
+                },
+                None => quote! {
+                    let #field_ident = <#storage_ty as leptos_controls::FieldStorage<#ty>>::create(#field_ident, __form);
+                },
+            }
+        });
+
+        // 计算方法在字段的初始值之前声明,避免与字段同名时被覆盖
+        let declare_compute_tokens = options.control_fields().filter_map(|field| {
+            let compute = field.compute()?;
+            let ty = field.ty();
+            let compute_ident = format_ident!("__compute_{}", field.ident());
+            Some(quote_spanned! {compute.span()=>
+                let #compute_ident: fn(&#control_struct_ident) -> #ty = #compute;
+            })
+        });
+
+        // 单个信号时非切片字段从结构体中复制初始值
+        let (declare_value_tokens, root_field_tokens) = if options.single() {
+            let clone_value_tokens = options
+                .control_fields()
+                .filter(|field| !options.is_slice(field))
+                .map(|field| {
+                    let field_ident = field.ident();
+                    quote! {
+                        let #field_ident = leptos::SignalWithUntracked::with_untracked(&__root, |value| value.#field_ident.clone());
+                    }
+                });
+            (
                 quote! {
-                    let #field_ident = leptos_controls::RwSignalField::new(#field_ident);
-                }
+                    #(#clone_value_tokens)*
+                },
+                quote! { __root, },
+            )
+        } else {
+            (
+                quote! {
+                    let #ident { #(#value_field_tokens,)* #(#stored_field_tokens,)* .. } = value;
+                },
+                quote! {},
+            )
+        };
+
+        // 计算字段依赖的控制器
+        let (declare_controls_tokens, store_controls_tokens) = if has_compute {
+            (
+                quote! {
+                    let __controls = leptos::store_value(None::<#control_struct_ident>);
+                },
+                quote! {
+                    __controls.set_value(Some(controls));
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+        // 保存跳过字段
+        let store_value_tokens = options.stored_fields().map(|field| {
+            let field_ident = field.ident();
+            quote! {
+                let #field_ident = leptos::store_value(#field_ident);
             }
         });
 
         // rest函数
         let fn_reset_tokens = options
+            .control_fields()
+            .filter(|field| !field.readonly())
+            .map(|field| {
+                let field_ident = field.ident();
+                let storage_ty = options.storage_ty(field);
+                quote! {
+                    <#storage_ty as leptos_controls::Field>::set_default(&self.#field_ident);
+                }
+            });
+
+        // snapshot函数
+        let get_untracked_tokens = options.control_fields().map(|field| {
+            let field_ident = field.ident();
+            let snapshot = options.snapshot_tokens(field, quote! { #field_ident });
+            quote! {
+                let #field_ident = #snapshot;
+            }
+        });
+
+        // 跳过字段的值
+        let skip_value_tokens = options
             .fields()
             .iter()
-            .filter(|field| !field.readonly())
+            .filter(|field| field.skip())
             .map(|field| {
                 let ty = field.ty();
                 let field_ident = field.ident();
-                let field_struct_ident = field.struct_ident(ident);
-                if field.readonly() {
-                    quote! {
-                        <leptos_controls::SignalField<#field_struct_ident, #ty> as leptos_controls::Field>::set_default(&self.#field_ident);
-                    }
-                } else {
+                match field.skip_default() {
+                    Some(default) => quote! {
+                        let #field_ident: #ty = #default;
+                    },
+                    None => quote! {
+                        let #field_ident = leptos::StoredValue::get_value(&#field_ident);
+                    },
+                }
+            });
+
+        // 单个信号时直接复制结构体,再覆盖非切片字段
+        let fn_snapshot_body = if options.single() {
+            let overlay_tokens = options
+                .control_fields()
+                .filter(|field| !options.is_slice(field))
+                .map(|field| {
+                    let field_ident = field.ident();
+                    let snapshot = options.snapshot_tokens(field, quote! { self.#field_ident });
                     quote! {
-                        <leptos_controls::RwSignalField<#field_struct_ident, #ty> as leptos_controls::Field>::set_default(&self.#field_ident);
+                        value.#field_ident = #snapshot;
                     }
-                }
+                });
+            let default_tokens = options.fields().iter().filter_map(|field| {
+                let default = field.skip_default()?;
+                let field_ident = field.ident();
+                Some(quote! {
+                    value.#field_ident = #default;
+                })
             });
+            quote! {
+                #[allow(unused_mut)]
+                let mut value = leptos::SignalGetUntracked::get_untracked(&self.__root);
+                #(#overlay_tokens)*
+                #(#default_tokens)*
+                value
+            }
+        } else {
+            quote! {
+                let #control_struct_ident { #(#field_tokens,)* #(#stored_field_tokens,)* .. } = *self;
+                #(#get_untracked_tokens)*
+                #(#skip_value_tokens)*
+                #ident {
+                    #(#all_field_tokens,)*
+                }
+            }
+        };
 
-        // snapshot函数
-        let get_untracked_tokens = options.fields().iter().map(|field| {
+        // 值变更回调
+        let on_change_tokens = options.control_fields().filter_map(|field| {
+            let on_change = field.on_change()?;
             let ty = field.ty();
             let field_ident = field.ident();
-            let field_struct_ident = field.struct_ident(ident);
-            if field.readonly() {
-                quote! {
-                    let #field_ident = <leptos_controls::SignalField<#field_struct_ident,#ty> as leptos::SignalGetUntracked>::get_untracked(&#field_ident);
+            let storage_ty = options.storage_ty(field);
+            let snapshot = options.snapshot_tokens(field, quote! { #field_ident });
+            let on_change = quote_spanned! {on_change.span()=>
+                let on_change: fn(&#ty, Option<&#ty>) = #on_change;
+            };
+            Some(quote! {
+                {
+                    #on_change
+                    _ = leptos::watch(
+                        move || {
+                            <#storage_ty as leptos_controls::Field>::track(&#field_ident);
+                            #snapshot
+                        },
+                        move |new, old, _| {
+                            if !__form.syncing() {
+                                on_change(new, old)
+                            }
+                        },
+                        false,
+                    );
                 }
-            } else {
+            })
+        });
+
+        // 依赖字段变更时重置或者重新校验,和外部信号同步期间不处理,避免把重置写回外部信号
+        let dependency_tokens = options.control_fields().flat_map(|field| {
+            let field_ident = field.ident();
+            let storage_ty = options.storage_ty(field);
+            let action = match field.on_dep_change() {
+                DependencyAction::Reset => quote! {
+                    <#storage_ty as leptos_controls::Field>::set_default(&#field_ident)
+                },
+                DependencyAction::Revalidate => quote! {
+                    <#storage_ty as leptos_controls::Field>::revalidate(&#field_ident)
+                },
+            };
+            field.depends_on().iter().map(move |dependency| {
                 quote! {
-                    let #field_ident = <leptos_controls::RwSignalField<#field_struct_ident,#ty> as leptos::SignalGetUntracked>::get_untracked(&#field_ident);
+                    _ = leptos::watch(
+                        move || leptos_controls::Field::track(&#dependency),
+                        move |_, _, _| {
+                            if !__form.syncing() {
+                                #action
+                            }
+                        },
+                        false,
+                    );
+                }
+            })
+        });
+
+        // 监听所有字段
+        let watch_any_tokens = options.control_fields().map(|field| {
+            let field_ident = field.ident();
+            let name = field.name();
+            let storage_ty = options.storage_ty(field);
+            quote! {
+                {
+                    let f = f.clone();
+                    let field = self.#field_ident;
+                    let stop = leptos::watch(
+                        move || <#storage_ty as leptos_controls::Field>::track(&field),
+                        move |_, _, _| f(#name, &field),
+                        false,
+                    );
+                    std::rc::Rc::new(stop) as std::rc::Rc<dyn Fn()>
                 }
             }
         });
 
+        // 按照聚焦顺序排列需要校验的字段
+        let mut focus_fields = options
+            .control_fields()
+            .filter(|field| field.validate().is_some())
+            .collect::<Vec<_>>();
+        focus_fields.sort_by_key(|field| field.focus_order());
+        let focus_field_tokens = focus_fields.iter().map(|field| {
+            let field_ident = field.ident();
+            quote! {
+                &self.#field_ident as &dyn leptos_controls::Field
+            }
+        });
+
+        let focus_field_count = focus_fields.len();
+
         let fn_validate_body = match options.validate() {
             Some(validate_fn) => {
                 quote! {
                     #validate_fn(&self)
                 }
-            },
+            }
             None => {
                 // validate函数
                 let fn_validate_tokens = options
-                    .fields()
-                    .iter()
+                    .control_fields()
                     .filter(|field| field.validate().is_some())
                     .map(|field| {
-                        let ty = field.ty();
                         let field_ident = field.ident();
-                        let field_struct_ident = field.struct_ident(ident);
-                        if field.readonly() {
-                            quote! {
-                                <leptos_controls::SignalField<#field_struct_ident, #ty> as leptos_controls::Field>::validate(&#field_ident)
-                            }
-                        } else {
-                            quote! {
-                                <leptos_controls::RwSignalField<#field_struct_ident, #ty> as leptos_controls::Field>::validate(&#field_ident)
-                            }
+                        let storage_ty = options.storage_ty(field);
+                        quote! {
+                            <#storage_ty as leptos_controls::Field>::validate(&#field_ident)
                         }
                     })
                     .collect::<Vec<_>>();
@@ -129,41 +358,395 @@ impl<'a> From<ControlStruct<'a>> for TokenStream {
             }
         };
 
+        let fn_new_body = quote! {
+            #(#declare_compute_tokens)*
+            #declare_value_tokens
+            let __form = leptos_controls::FormState::new(#mode);
+            #declare_controls_tokens
+            #(#set_signal_tokens)*
+            #(#store_value_tokens)*
+            let controls = #control_struct_ident {
+                #(#field_tokens,)*
+                #(#stored_field_tokens,)*
+                #root_field_tokens
+                __form,
+                __owner,
+            };
+            #store_controls_tokens
+            #(#on_change_tokens)*
+            #(#dependency_tokens)*
+            controls
+        };
+
+        // 构造函数,控制器创建在当前所有者的子所有者中
+        let has_sources = options.source_fields().next().is_some();
+        let sources_struct_ident = options.sources_struct_ident();
+        let fn_with_sources_doc = "Construct a new instance from arguments, the readonly fields bound in `sources` will read from the external signals";
+        let fn_new_tokens = if options.single() {
+            let fn_bind_doc = "Construct a new instance bound to an external signal, the fields read from and write through to the signal";
+            if has_sources {
+                let slice_tokens = options.source_fields().map(|field| {
+                    let field_ident = field.ident();
+                    quote! {
+                        #field_ident: Some(__root.slice(|value| &value.#field_ident))
+                    }
+                });
+                quote! {
+                    #[doc = "Construct a new instance from arguments"]
+                    pub fn new(value: #ident) -> Self {
+                        Self::with_sources(value, Default::default())
+                    }
+
+                    #[doc = #fn_with_sources_doc]
+                    pub fn with_sources(value: #ident, sources: #sources_struct_ident) -> Self {
+                        leptos_controls::ControlsOwner::scope((value, sources), |(value, sources), owner| {
+                            Self::from_root(leptos_controls::SliceRoot::new(value), sources, owner)
+                        })
+                    }
+
+                    #[doc = #fn_bind_doc]
+                    pub fn bind(source: leptos::RwSignal<#ident>) -> Self {
+                        leptos_controls::ControlsOwner::scope(source, |source, owner| {
+                            let __root = leptos_controls::SliceRoot::bind(source);
+                            let sources = #sources_struct_ident { #(#slice_tokens,)* };
+                            Self::from_root(__root, sources, owner)
+                        })
+                    }
+
+                    fn from_root(
+                        __root: leptos_controls::SliceRoot<#ident>,
+                        __sources: #sources_struct_ident,
+                        __owner: leptos_controls::ControlsOwner,
+                    ) -> Self {
+                        #fn_new_body
+                    }
+                }
+            } else {
+                quote! {
+                    #[doc = "Construct a new instance from arguments"]
+                    pub fn new(value: #ident) -> Self {
+                        leptos_controls::ControlsOwner::scope(value, |value, owner| {
+                            Self::from_root(leptos_controls::SliceRoot::new(value), owner)
+                        })
+                    }
+
+                    #[doc = #fn_bind_doc]
+                    pub fn bind(source: leptos::RwSignal<#ident>) -> Self {
+                        leptos_controls::ControlsOwner::scope(source, |source, owner| {
+                            Self::from_root(leptos_controls::SliceRoot::bind(source), owner)
+                        })
+                    }
+
+                    fn from_root(
+                        __root: leptos_controls::SliceRoot<#ident>,
+                        __owner: leptos_controls::ControlsOwner,
+                    ) -> Self {
+                        #fn_new_body
+                    }
+                }
+            }
+        } else {
+            // 只读字段从外部信号中读取
+            let bind_sources_tokens = if has_sources {
+                let source_tokens = options.source_fields().map(|field| {
+                    let field_ident = field.ident();
+                    quote! {
+                        #field_ident: Some(leptos::Signal::derive(move || {
+                            leptos::SignalWith::with(&__source, |value| value.#field_ident.clone())
+                        }))
+                    }
+                });
+                quote! {
+                    let __sources = #sources_struct_ident { #(#source_tokens,)* };
+                }
+            } else {
+                quote! {}
+            };
+
+            // 可写字段和外部信号双向同步,同步期间忽略反向的变更
+            let synced_field_tokens = options
+                .synced_fields()
+                .map(|field| field.ident())
+                .collect::<Vec<_>>();
+            let sync_field_tokens = options.synced_fields().map(|field| {
+                let field_ident = field.ident();
+                let storage_ty = options.storage_ty(field);
+                quote! {
+                    _ = leptos::watch(
+                        move || <#storage_ty as leptos_controls::Field>::track(&#field_ident),
+                        move |_, _, _| {
+                            if !__form.syncing() {
+                                let value = leptos::SignalGetUntracked::get_untracked(&#field_ident);
+                                __form.sync(|| {
+                                    leptos::SignalUpdate::update(&__source, |source| source.#field_ident = value)
+                                });
+                            }
+                        },
+                        false,
+                    );
+                }
+            });
+            // 外部信号变更时只同步值变化的字段
+            let sync_source_tokens = options.synced_fields().map(|field| {
+                let field_ident = field.ident();
+                let field_struct_ident = field.struct_ident(ident);
+                quote! {
+                    let changed = leptos::SignalWithUntracked::with_untracked(&#field_ident, |value| {
+                        !<#field_struct_ident as leptos_controls::FieldMeta>::EQ(value, &source.#field_ident)
+                    });
+                    if changed {
+                        #field_ident.sync(source.#field_ident.clone());
+                    }
+                }
+            });
+            // 跳过的字段不会渲染,直接跟随外部信号
+            let sync_stored_tokens = options.stored_fields().map(|field| {
+                let field_ident = field.ident();
+                quote! {
+                    leptos::StoredValue::set_value(&#field_ident, source.#field_ident.clone());
+                }
+            });
+
+            let fn_bind_doc = "Construct a new instance bound to an external signal, the writable fields are synchronized with the signal in both directions, the fields with custom storage only read the initial value and the skipped fields without a default follow the signal";
+            // 不带生命周期的`#ident: Clone`在结构体没有实现`Clone`时会直接编译失败,
+            // 加上`for<'a>`后约束推迟到调用时检查,没有实现`Clone`的结构体只是不能调用`bind`
+            let fn_bind_tokens = quote! {
+                #[doc = #fn_bind_doc]
+                pub fn bind(source: leptos::RwSignal<#ident>) -> Self
+                where
+                    for<'a> #ident: Clone,
+                {
+                    leptos_controls::ControlsOwner::scope(source, |__source, __owner| {
+                        let value = leptos::SignalGetUntracked::get_untracked(&__source);
+                        #bind_sources_tokens
+                        let controls = { #fn_new_body };
+                        #[allow(unused_variables)]
+                        let #control_struct_ident { #(#synced_field_tokens,)* #(#stored_field_tokens,)* __form, .. } = controls;
+                        #(#sync_field_tokens)*
+                        _ = leptos::watch(
+                            move || leptos::SignalWith::track(&__source),
+                            move |_, _, _| {
+                                if !__form.syncing() {
+                                    __form.sync(|| {
+                                        leptos::SignalWithUntracked::with_untracked(&__source, |source| {
+                                            #(#sync_source_tokens)*
+                                            #(#sync_stored_tokens)*
+                                        })
+                                    });
+                                }
+                            },
+                            false,
+                        );
+                        controls
+                    })
+                }
+            };
+
+            if has_sources {
+                quote! {
+                    #[doc = "Construct a new instance from arguments"]
+                    pub fn new(value: #ident) -> Self {
+                        Self::with_sources(value, Default::default())
+                    }
+
+                    #[doc = #fn_with_sources_doc]
+                    pub fn with_sources(value: #ident, sources: #sources_struct_ident) -> Self {
+                        leptos_controls::ControlsOwner::scope((value, sources), |(value, __sources), __owner| {
+                            #fn_new_body
+                        })
+                    }
+
+                    #fn_bind_tokens
+                }
+            } else {
+                quote! {
+                    #[doc = "Construct a new instance from arguments"]
+                    pub fn new(value: #ident) -> Self {
+                        leptos_controls::ControlsOwner::scope(value, |value, __owner| {
+                            #fn_new_body
+                        })
+                    }
+
+                    #fn_bind_tokens
+                }
+            }
+        };
+
+        // 通过字段枚举访问字段
+        let field_enum_ident = options.field_enum_ident();
+        let field_match_tokens = options.control_fields().map(|field| {
+            let field_ident = field.ident();
+            let variant = field.variant_ident();
+            quote! {
+                #field_enum_ident::#variant => &self.#field_ident
+            }
+        });
+
+        let field_name_tokens = options.control_fields().map(|field| field.name());
+        let controls_id = options.id();
+
         quote! {
             #[derive(Clone, Copy)]
             #vis struct #control_struct_ident {
                 #(#field_with_type_tokens,)*
+                #(#stored_field_with_type_tokens,)*
+                #root_field_type_tokens
+                __form: leptos_controls::FormState,
+                __owner: leptos_controls::ControlsOwner,
             }
 
             impl #control_struct_ident {
 
-                #[doc = "Construct a new instance from arguments"]
-                pub fn new(value: #ident) -> Self {
-                  let #ident { #(#field_tokens,)*.. }  = value;
-                    #(#set_signal_tokens)*
-                    #control_struct_ident {
-                        #(#field_tokens,)*
+                #fn_new_tokens
+
+                #[doc = "Dispose all signals of the controls, after disposal the `try_*` accessors of fields return `None`"]
+                pub fn dispose(&self) {
+                    self.__owner.dispose();
+                }
+
+                #[doc = "Whether the controls have been disposed, by `dispose` or with their parent owner"]
+                pub fn is_disposed(&self) -> bool {
+                    self.__owner.is_disposed()
+                }
+
+                #[doc = "Provide the controls and the form state as context for the descendant components"]
+                pub fn provide(&self) {
+                    leptos::provide_context(*self);
+                    self.__form.provide();
+                }
+
+                #[doc = "Get the controls provided by an ancestor component"]
+                pub fn use_context() -> Option<Self> {
+                    leptos::use_context::<Self>()
+                }
+
+                #[doc = "Get the controls provided by an ancestor component, panic if not exists"]
+                pub fn expect_context() -> Self {
+                    leptos::expect_context::<Self>()
+                }
+
+                #[doc = "Get the field by the field enum"]
+                pub fn field(&self, field: #field_enum_ident) -> &dyn leptos_controls::AnyField {
+                    match field {
+                        #(#field_match_tokens,)*
                     }
                 }
 
+                #[doc = "Iterate all fields with the field enum in declaration order"]
+                pub fn fields(&self) -> impl Iterator<Item = (#field_enum_ident, &dyn leptos_controls::AnyField)> + '_ {
+                    #field_enum_ident::ALL
+                        .into_iter()
+                        .map(move |field| (field, self.field(field)))
+                }
+
+                #[doc = "Get the field by the field name, return `None` if the name is unknown"]
+                pub fn get_field(&self, name: &str) -> Option<&dyn leptos_controls::AnyField> {
+                    name.parse::<#field_enum_ident>().ok().map(|field| self.field(field))
+                }
+
+                #[doc = "Get the form state shared by all fields"]
+                pub fn form(&self) -> leptos_controls::FormState {
+                    self.__form
+                }
+
+                #[doc = "Watch all fields change with the field name, return a function to stop watching"]
+                pub fn watch_any(&self, f: impl Fn(&'static str, &dyn leptos_controls::Field) + 'static) -> impl Fn() + Clone {
+                    let f = std::rc::Rc::new(f);
+                    let stops: Vec<std::rc::Rc<dyn Fn()>> = vec![#(#watch_any_tokens,)*];
+                    move || stops.iter().for_each(|stop| stop())
+                }
+
                 #[doc = "Set controls all values use default value"]
                 pub fn set_default(&self) {
                     #(#fn_reset_tokens)*
+                    self.__form.reset();
                 }
 
                 #[doc = "Get controls all values use untracked"]
                 pub fn snapshot(&self) -> #ident {
-                    let #control_struct_ident { #(#field_tokens,)* .. } = *self;
-                    #(#get_untracked_tokens)*
-                    #ident{
-                        #(#field_tokens,)*
-                    }
+                    #fn_snapshot_body
                 }
 
-                #[doc = "Validate controls all field and return error messages"]
+                #[doc = "Validate controls all field and return error messages, the controls will be marked as submitted"]
                 pub fn validate(&self) -> Vec<std::borrow::Cow<'static,str>> {
+                    self.__form.submit();
                     #fn_validate_body
                 }
+
+                #[doc = "Focus the first invalid field which has registered a node reference, return whether a field has been focused"]
+                pub fn focus_first_error(&self) -> bool {
+                    let fields: [&dyn leptos_controls::Field; #focus_field_count] = [#(#focus_field_tokens,)*];
+                    fields
+                        .into_iter()
+                        .any(|field| field.validate().is_some() && field.focus())
+                }
+
+                #[doc = "Validate controls and get all values use untracked if there is no error"]
+                pub fn try_snapshot(&self) -> Result<#ident, leptos_controls::ValidationErrors> {
+                    let errors = self.validate();
+                    if errors.is_empty() {
+                        Ok(self.snapshot())
+                    } else {
+                        Err(leptos_controls::ValidationErrors::from(errors))
+                    }
+                }
+
+                #[doc = "Validate controls and convert all values into the target type use `TryFrom`"]
+                pub fn try_snapshot_into<U>(&self) -> Result<U, leptos_controls::ValidationErrors>
+                where
+                    U: TryFrom<#ident>,
+                    <U as TryFrom<#ident>>::Error: Into<leptos_controls::ValidationErrors>,
+                {
+                    self.try_snapshot().and_then(|value| U::try_from(value).map_err(Into::into))
+                }
+            }
+
+            impl leptos_controls::Controls for #control_struct_ident {
+                type Value = #ident;
+
+                const FIELDS: &'static [&'static str] = &[#(#field_name_tokens,)*];
+
+                const ID: &'static str = #controls_id;
+
+                fn new(value: #ident) -> Self {
+                    Self::new(value)
+                }
+
+                fn set_default(&self) {
+                    Self::set_default(self)
+                }
+
+                fn snapshot(&self) -> #ident {
+                    Self::snapshot(self)
+                }
+
+                fn validate(&self) -> Vec<std::borrow::Cow<'static, str>> {
+                    Self::validate(self)
+                }
+
+                fn get_field(&self, name: &str) -> Option<&dyn leptos_controls::AnyField> {
+                    Self::get_field(self, name)
+                }
+
+                fn form(&self) -> leptos_controls::FormState {
+                    self.__form
+                }
+
+                fn is_dirty(&self) -> bool {
+                    self.fields().any(|(_, field)| field.is_dirty())
+                }
+
+                fn errors(&self) -> Vec<std::borrow::Cow<'static, str>> {
+                    self.fields().filter_map(|(_, field)| field.error()).collect()
+                }
+
+                fn dispose(&self) {
+                    Self::dispose(self)
+                }
+
+                fn focus_first_error(&self) -> bool {
+                    Self::focus_first_error(self)
+                }
             }
         }
     }
@@ -1,4 +1,7 @@
 use crate::field::FieldStruct;
+use crate::field_enum::FieldEnum;
+use crate::sources::SourcesStruct;
+use crate::thaw_form::ThawForm;
 use crate::{control::ControlStruct, options::ControlOptions};
 use darling::{Error, FromDeriveInput};
 use proc_macro2::TokenStream;
@@ -32,7 +35,16 @@ pub fn derive(input: DeriveInput) -> TokenStream {
 
 fn try_expand(input: &DeriveInput) -> Result<TokenStream, Error> {
     let options = ControlOptions::from_derive_input(input).and_then(ControlOptions::verify)?;
-    let field_enum = TokenStream::from(FieldStruct::new(&options));
+    let field_struct = TokenStream::from(FieldStruct::new(&options));
+    let field_enum = TokenStream::from(FieldEnum::new(&options));
+    let sources_struct = TokenStream::from(SourcesStruct::new(&options));
     let control_struct = TokenStream::from(ControlStruct::new(&options));
-    Ok(TokenStream::from_iter([field_enum, control_struct]))
+    let thaw_form = TokenStream::from(ThawForm::new(&options));
+    Ok(TokenStream::from_iter([
+        field_struct,
+        field_enum,
+        sources_struct,
+        control_struct,
+        thaw_form,
+    ]))
 }
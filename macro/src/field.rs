@@ -1,9 +1,8 @@
-use darling::FromMeta;
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::Path;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 
-use crate::options::ControlOptions;
+use crate::options::{ControlOptions, Widget};
 
 pub struct FieldStruct<'a> {
     options: &'a ControlOptions,
@@ -21,7 +20,7 @@ impl<'a> From<FieldStruct<'a>> for TokenStream {
         let ident = options.ident();
         let vis = options.vis();
         // 字段结构体
-        let field_struct_tokens = options.fields().iter().map(|field| {
+        let field_struct_tokens = options.control_fields().map(|field| {
             let field_struct_ident = field.struct_ident(ident);
             quote! {
                #[doc(hidden)]
@@ -31,7 +30,7 @@ impl<'a> From<FieldStruct<'a>> for TokenStream {
         });
 
         // 实现FieldMeta
-        let impl_field_meta_tokens = options.fields().iter().map(|field| {
+        let impl_field_meta_tokens = options.control_fields().map(|field| {
             let ty = field.ty();
             let field_struct_ident = field.struct_ident(ident);
             let label = field.label();
@@ -39,7 +38,10 @@ impl<'a> From<FieldStruct<'a>> for TokenStream {
             let message = field.message();
             let validate = match field.validate() {
                 Some(validate) => {
-                    let validate = Path::from_string(validate).unwrap();
+                    // 显式声明函数类型,使闭包可以推断参数类型,同时类型不匹配时错误指向校验方法
+                    let validate = quote_spanned! {validate.span()=>
+                        let validate: fn(&#ty) -> bool = #validate;
+                    };
                     let error = match message {
                         Some(message) => quote! {
                             std::borrow::Cow::from(#message)
@@ -49,8 +51,9 @@ impl<'a> From<FieldStruct<'a>> for TokenStream {
                         }
                     };
                     quote! {
-                        move |v|{
-                          if #validate(v) {
+                        move |v| {
+                            #validate
+                            if validate(v) {
                                 None
                             }else{
                                 Some(#error)
@@ -62,12 +65,96 @@ impl<'a> From<FieldStruct<'a>> for TokenStream {
                     move |v| None
                 },
             };
+            let mode = match field.mode() {
+                Some(mode) => quote! { Some(#mode) },
+                None => quote! { None },
+            };
+            // 无法确定的类型在运行时根据是否实现了`SelectOptions`选择
+            let (widget, options) = match field.known_widget() {
+                Some(Widget::Select) => (
+                    quote! { || leptos_controls::Widget::Select },
+                    quote! {
+                        || {
+                            <#ty as leptos_controls::SelectOptions>::options()
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect()
+                        }
+                    },
+                ),
+                Some(widget) => (quote! { || #widget }, quote! { Vec::new }),
+                None => (
+                    quote! {
+                        || {
+                            #[allow(unused_imports)]
+                            use leptos_controls::convert::{NoSelectOptions, ViaSelectOptions};
+                            (&leptos_controls::convert::SelectWrap::<#ty>(std::marker::PhantomData)).widget()
+                        }
+                    },
+                    quote! {
+                        || {
+                            #[allow(unused_imports)]
+                            use leptos_controls::convert::{NoSelectOptions, ViaSelectOptions};
+                            (&leptos_controls::convert::SelectWrap::<#ty>(std::marker::PhantomData)).options()
+                        }
+                    },
+                ),
+            };
+            // `Option<String>`没有实现`Display`和`FromStr`,空字符串对应`None`
+            let convert = if field.is_optional_string() {
+                quote! {
+                    const DISPLAY: fn(&Self::Type) -> Option<String> = |value| {
+                        Some(value.clone().unwrap_or_default())
+                    };
+                    const PARSE: fn(&str) -> Result<Self::Type, std::borrow::Cow<'static, str>> = |value| {
+                        Ok((!value.is_empty()).then(|| value.to_owned()))
+                    };
+                }
+            } else {
+                quote! {
+                    const DISPLAY: fn(&Self::Type) -> Option<String> = |value| {
+                        #[allow(unused_imports)]
+                        use leptos_controls::convert::{NoDisplay, ViaDisplay};
+                        (&leptos_controls::convert::DisplayWrap(value)).to_value_string()
+                    };
+                    const PARSE: fn(&str) -> Result<Self::Type, std::borrow::Cow<'static, str>> = |value| {
+                        #[allow(unused_imports)]
+                        use leptos_controls::convert::{NoFromStr, ViaFromStr};
+                        (&leptos_controls::convert::ParseWrap::<#ty>(std::marker::PhantomData)).parse_value(value)
+                    };
+                }
+            };
+            let placeholder = match field.placeholder() {
+                Some(placeholder) => quote! { Some(#placeholder) },
+                None => quote! { None },
+            };
+            let help = match field.help() {
+                Some(help) => quote! { Some(#help) },
+                None => quote! { None },
+            };
+            let hidden = field.hidden();
+            let order = field.order();
+            let attrs = field.attrs().map(|(name, value)| quote! { (#name, #value) });
             quote! {
                 impl leptos_controls::FieldMeta for #field_struct_ident {
                     type Type = #ty;
                     const LABEL: &'static str = #label;
                     const REQUIRED: bool = #required;
                     const VALIDATE: fn(&Self::Type) -> Option<std::borrow::Cow<'static, str>> = #validate;
+                    const MODE: Option<leptos_controls::ValidationMode> = #mode;
+                    #convert
+                    const EQ: fn(&Self::Type, &Self::Type) -> bool = |value, other| {
+                        #[allow(unused_imports)]
+                        use leptos_controls::convert::{NoPartialEq, ViaPartialEq};
+                        (&leptos_controls::convert::EqWrap(value, other)).value_eq()
+                    };
+                    const WIDGET: fn() -> leptos_controls::Widget = #widget;
+                    const OPTIONS: fn() -> Vec<String> = #options;
+                    const PLACEHOLDER: Option<&'static str> = #placeholder;
+                    const HELP: Option<&'static str> = #help;
+                    const HIDDEN: bool = #hidden;
+                    const ORDER: i32 = #order;
+                    const ATTRS: &'static [(&'static str, &'static str)] = &[#(#attrs,)*];
                 }
             }
         });
@@ -0,0 +1,100 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::options::ControlOptions;
+
+pub struct FieldEnum<'a> {
+    options: &'a ControlOptions,
+}
+
+impl<'a> FieldEnum<'a> {
+    pub fn new(options: &'a ControlOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> From<FieldEnum<'a>> for TokenStream {
+    fn from(value: FieldEnum<'a>) -> Self {
+        let options = value.options;
+        let ident = options.ident();
+        let vis = options.vis();
+        let field_enum_ident = options.field_enum_ident();
+        let enum_doc = format!("All fields of `{}` which have a control", ident);
+        let variants = options
+            .control_fields()
+            .map(|field| field.variant_ident())
+            .collect::<Vec<_>>();
+        let variant_count = variants.len();
+
+        // 字段名称
+        let name_tokens = options.control_fields().map(|field| {
+            let variant = field.variant_ident();
+            let name = field.name();
+            quote! {
+                #field_enum_ident::#variant => #name
+            }
+        });
+
+        // 字段标签
+        let label_tokens = options.control_fields().map(|field| {
+            let variant = field.variant_ident();
+            let field_struct_ident = field.struct_ident(ident);
+            quote! {
+                #field_enum_ident::#variant => <#field_struct_ident as leptos_controls::FieldMeta>::LABEL
+            }
+        });
+
+        // 通过名称解析
+        let from_str_tokens = options.control_fields().map(|field| {
+            let variant = field.variant_ident();
+            let name = field.name();
+            quote! {
+                #name => Ok(#field_enum_ident::#variant)
+            }
+        });
+
+        quote! {
+            #[doc = #enum_doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #vis enum #field_enum_ident {
+                #(#variants,)*
+            }
+
+            impl #field_enum_ident {
+                #[doc = "All fields in declaration order"]
+                pub const ALL: [#field_enum_ident; #variant_count] = [#(#field_enum_ident::#variants,)*];
+
+                #[doc = "Get the field name"]
+                pub fn name(&self) -> &'static str {
+                    match *self {
+                        #(#name_tokens,)*
+                    }
+                }
+
+                #[doc = "Get the field label"]
+                pub fn label(&self) -> &'static str {
+                    match *self {
+                        #(#label_tokens,)*
+                    }
+                }
+            }
+
+            impl std::fmt::Display for #field_enum_ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            impl std::str::FromStr for #field_enum_ident {
+                type Err = leptos_controls::UnknownField;
+
+                fn from_str(name: &str) -> Result<Self, Self::Err> {
+                    match name {
+                        #(#from_str_tokens,)*
+                        _ => Err(leptos_controls::UnknownField::new(name)),
+                    }
+                }
+            }
+        }
+    }
+}
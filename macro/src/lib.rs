@@ -1,7 +1,10 @@
 mod control;
 mod expand;
 mod field;
+mod field_enum;
 mod options;
+mod sources;
+mod thaw_form;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
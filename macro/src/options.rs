@@ -1,11 +1,20 @@
+use darling::ast::NestedMeta;
+use darling::util::{Override, SpannedValue};
 use darling::{ast, Error, FromDeriveInput, FromField, FromMeta};
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, ToTokens};
 use std::borrow::Cow;
-use syn::{Type, Visibility};
+use syn::ext::IdentExt;
+use syn::{Expr, GenericArgument, Lit, Meta, PathArguments, Type, Visibility};
 
 const CONTROL_IDENT: &str = "Controls";
 
+const SOURCES_IDENT: &str = "Sources";
+
+const FIELD_IDENT: &str = "Field";
+
+const FORM_IDENT: &str = "Form";
+
 #[derive(FromDeriveInput)]
 #[darling(attributes(controls, field), supports(struct_named))]
 pub struct ControlOptions {
@@ -29,6 +38,24 @@ pub struct ControlOptions {
     ///
     #[darling(default)]
     validate: Option<syn::Path>,
+
+    ///
+    /// 校验模式
+    ///
+    #[darling(default)]
+    mode: Option<ValidationMode>,
+
+    ///
+    /// 存储方式
+    ///
+    #[darling(default)]
+    storage: Storage,
+
+    ///
+    /// 是否生成thaw表单组件
+    ///
+    #[darling(default)]
+    thaw_form: bool,
 }
 
 impl ControlOptions {
@@ -40,6 +67,35 @@ impl ControlOptions {
         &self.ident
     }
 
+    ///
+    /// 字段关联元素id的前缀,类型名称的kebab-case,例如`UserProfile`为`user-profile`、`HTTPConfig`为`http-config`
+    ///
+    pub fn id(&self) -> String {
+        let name = self.ident.unraw().to_string();
+        let chars = name.chars().collect::<Vec<_>>();
+        let mut id = String::with_capacity(name.len() + 4);
+        for (index, &c) in chars.iter().enumerate() {
+            if c == '_' {
+                if !id.is_empty() && !id.ends_with('-') {
+                    id.push('-');
+                }
+                continue;
+            }
+            if c.is_uppercase() && index > 0 && !id.ends_with('-') {
+                let prev = chars[index - 1];
+                let next_lower = chars.get(index + 1).is_some_and(|c| c.is_lowercase());
+                if prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_lower)
+                {
+                    id.push('-');
+                }
+            }
+            id.extend(c.to_lowercase());
+        }
+        id.trim_end_matches('-').to_string()
+    }
+
     pub fn fields(&self) -> &[ControlFieldOptions] {
         match &self.data {
             ast::Data::Enum(_) => unreachable!(),
@@ -47,17 +103,120 @@ impl ControlOptions {
         }
     }
 
+    ///
+    /// 需要生成控制器的字段集合(排除忽略的字段)
+    ///
+    pub fn control_fields(&self) -> impl Iterator<Item = &ControlFieldOptions> {
+        self.fields().iter().filter(|field| !field.skip())
+    }
+
+    ///
+    /// 跳过并且需要保留原始值的字段集合
+    ///
+    pub fn stored_fields(&self) -> impl Iterator<Item = &ControlFieldOptions> {
+        let single = self.single();
+        self.fields()
+            .iter()
+            .filter(move |field| !single && field.skip() && field.skip_default().is_none())
+    }
+
+    ///
+    /// 是否使用单个信号保存整个结构体
+    ///
+    pub fn single(&self) -> bool {
+        matches!(self.storage, Storage::Single)
+    }
+
+    ///
+    /// 字段是否为结构体信号的切片
+    ///
+    pub fn is_slice(&self, field: &ControlFieldOptions) -> bool {
+        self.single() && !field.readonly() && field.with.is_none()
+    }
+
+    ///
+    /// 绑定外部信号时双向同步的字段集合(使用默认存储的可写字段)
+    ///
+    pub fn synced_fields(&self) -> impl Iterator<Item = &ControlFieldOptions> {
+        self.control_fields()
+            .filter(|field| !field.readonly() && field.with.is_none())
+    }
+
+    ///
+    /// 字段存储类型
+    ///
+    pub fn storage_ty(&self, field: &ControlFieldOptions) -> TokenStream {
+        if self.is_slice(field) {
+            let ident = &self.ident;
+            let ty = field.ty();
+            let field_struct_ident = field.struct_ident(ident);
+            quote! { leptos_controls::SliceField<#field_struct_ident, #ident, #ty> }
+        } else {
+            field.storage_ty(&self.ident)
+        }
+    }
+
+    ///
+    /// 获取字段当前值的表达式(不追踪)
+    ///
+    pub fn snapshot_tokens(&self, field: &ControlFieldOptions, expr: TokenStream) -> TokenStream {
+        let ty = field.ty();
+        let storage_ty = self.storage_ty(field);
+        if self.is_slice(field) {
+            quote! { <#storage_ty as leptos::SignalGetUntracked>::get_untracked(&#expr) }
+        } else {
+            quote! { <#storage_ty as leptos_controls::FieldStorage<#ty>>::snapshot(&#expr) }
+        }
+    }
+
     pub fn validate(&self) -> Option<&syn::Path> {
         self.validate.as_ref()
     }
 
+    pub fn mode(&self) -> TokenStream {
+        match self.mode {
+            Some(mode) => quote! { #mode },
+            None => quote! { leptos_controls::ValidationMode::default() },
+        }
+    }
+
     pub fn control_struct_ident(&self) -> Ident {
         format_ident!("{}{}", self.ident, CONTROL_IDENT)
     }
 
+    ///
+    /// 可以绑定外部信号的只读字段集合(排除计算字段)
+    ///
+    pub fn source_fields(&self) -> impl Iterator<Item = &ControlFieldOptions> {
+        self.control_fields()
+            .filter(|field| field.readonly() && field.compute().is_none())
+    }
+
+    pub fn sources_struct_ident(&self) -> Ident {
+        format_ident!("{}{}", self.ident, SOURCES_IDENT)
+    }
+
+    pub fn field_enum_ident(&self) -> Ident {
+        format_ident!("{}{}", self.ident, FIELD_IDENT)
+    }
+
+    pub fn thaw_form(&self) -> bool {
+        self.thaw_form
+    }
+
+    pub fn form_ident(&self) -> Ident {
+        format_ident!("{}{}", self.ident, FORM_IDENT)
+    }
+
     pub fn field_tokens(&self) -> Vec<TokenStream> {
-        self.fields()
-            .iter()
+        self.control_fields()
+            .map(|field| field.ident())
+            .map(|ident| quote! {#ident})
+            .collect()
+    }
+
+    pub fn stored_field_tokens(&self) -> Vec<TokenStream> {
+        self.stored_fields()
             .map(|field| field.ident())
             .map(|ident| quote! {#ident})
             .collect()
@@ -71,15 +230,74 @@ impl ControlOptions {
             ast::Data::Enum(_) => Err(Error::unexpected_type("Enum")),
             ast::Data::Struct(fields) => {
                 if fields.fields.iter().any(|field| field.ident.is_none()) {
-                    Err(Error::custom("Exists empty ident!"))
-                } else {
-                    Ok(self)
+                    return Err(Error::custom("Exists empty ident!"));
+                }
+                let mut errors = Error::accumulator();
+                for field in fields.fields.iter() {
+                    errors.handle(field.verify());
+                    errors.handle(self.verify_dependencies(field));
+                    if self.thaw_form {
+                        errors.handle(field.verify_thaw_form());
+                    }
                 }
+                errors.handle(self.verify_names());
+                errors.finish_with(self)
             }
         }
     }
+
+    ///
+    /// 校验字段依赖的字段是否存在
+    ///
+    fn verify_dependencies(&self, field: &ControlFieldOptions) -> Result<(), Error> {
+        let mut errors = Error::accumulator();
+        for dependency in field.depends_on() {
+            if dependency == field.ident() {
+                errors.push(Error::custom("field can't depend on itself").with_span(dependency));
+            } else if !self
+                .control_fields()
+                .any(|field| field.ident() == dependency)
+            {
+                errors.push(
+                    Error::custom(format!("unknown field `{}`", dependency)).with_span(dependency),
+                );
+            }
+        }
+        errors.finish()
+    }
+
+    ///
+    /// 校验字段生成的类型名称是否与其它生成的类型冲突
+    ///
+    fn verify_names(&self) -> Result<(), Error> {
+        let mut idents = vec![self.control_struct_ident(), self.field_enum_ident()];
+        if self.source_fields().next().is_some() {
+            idents.push(self.sources_struct_ident());
+        }
+        if self.thaw_form {
+            idents.push(self.form_ident());
+        }
+        let mut errors = Error::accumulator();
+        for field in self.control_fields() {
+            let struct_ident = field.struct_ident(&self.ident);
+            if idents.contains(&struct_ident) {
+                errors.push(
+                    Error::custom(format!(
+                        "field `{}` conflicts with the generated type `{}`, rename the field",
+                        field.ident(),
+                        struct_ident
+                    ))
+                    .with_span(field.ident()),
+                );
+            } else {
+                idents.push(struct_ident);
+            }
+        }
+        errors.finish()
+    }
 }
 
+#[allow(dead_code)]
 #[derive(Default, FromMeta)]
 #[darling(default)]
 pub struct ControlAttributes {
@@ -110,6 +328,24 @@ pub struct ControlFieldOptions {
     #[darling(default)]
     readonly: bool,
 
+    ///
+    /// 是否跳过(不生成控制器字段)
+    ///
+    #[darling(default)]
+    skip: SpannedValue<bool>,
+
+    ///
+    /// 跳过字段的默认值
+    ///
+    #[darling(default)]
+    default: Option<SpannedValue<Override<syn::Path>>>,
+
+    ///
+    /// 自定义字段存储类型
+    ///
+    #[darling(default)]
+    with: Option<syn::Path>,
+
     ///
     /// 字段标签
     ///
@@ -117,14 +353,94 @@ pub struct ControlFieldOptions {
     label: Option<String>,
 
     ///
-    /// 校验方法
+    /// 校验方法,支持路径(可以带引号)或者闭包表达式
     ///
-    validate: Option<String>,
+    validate: Option<ValidateFn>,
 
     ///
     /// 校验信息
     ///
     message: Option<String>,
+
+    ///
+    /// 校验模式,为空时使用控制器的校验模式
+    ///
+    #[darling(default)]
+    mode: Option<ValidationMode>,
+
+    ///
+    /// 值变更回调,支持路径(可以带引号)或者闭包表达式
+    ///
+    #[darling(default)]
+    on_change: Option<syn::Expr>,
+
+    ///
+    /// 依赖的字段
+    ///
+    #[darling(default)]
+    depends_on: Option<Dependencies>,
+
+    ///
+    /// 依赖的字段变更时的处理方式
+    ///
+    #[darling(default)]
+    on_dep_change: Option<SpannedValue<DependencyAction>>,
+
+    ///
+    /// 计算字段的计算方法,参数为控制器
+    ///
+    /// 读取时才会计算,控制器创建完成之前读取时返回传入的初始值。
+    ///
+    #[darling(default)]
+    compute: Option<syn::Expr>,
+
+    ///
+    /// `focus_first_error`查找校验失败字段的顺序,默认和`order`相同,相同时按照声明顺序
+    ///
+    /// 只影响聚焦,不改变渲染顺序,例如需要优先聚焦页面下方的关键字段时使用。
+    ///
+    #[darling(default)]
+    focus_order: Option<i32>,
+
+    ///
+    /// 自动渲染时的输入控件类型,为空时根据字段类型推断
+    ///
+    /// 实现了`SelectOptions`的类型推断为下拉选择框,`thaw_form`同样在运行时选择。
+    ///
+    #[darling(default)]
+    widget: Option<Widget>,
+
+    ///
+    /// 输入提示
+    ///
+    #[darling(default)]
+    placeholder: Option<String>,
+
+    ///
+    /// 帮助信息
+    ///
+    #[darling(default)]
+    help: Option<String>,
+
+    ///
+    /// 自动渲染时是否隐藏
+    ///
+    #[darling(default)]
+    hidden: bool,
+
+    ///
+    /// `AutoForm`和`thaw_form`渲染字段的顺序,默认为0,相同时按照声明顺序
+    ///
+    /// 同时作为`focus_order`的默认值,使聚焦的顺序和渲染的顺序一致。
+    ///
+    #[darling(default)]
+    order: i32,
+
+    ///
+    /// 输入元素的自定义属性,支持多次声明
+    ///
+    #[darling(default, multiple)]
+    attr: Vec<SpannedValue<Attrs>>,
 }
 
 impl ControlFieldOptions {
@@ -136,46 +452,551 @@ impl ControlFieldOptions {
         self.ident.as_ref().expect("Ident is not exists!")
     }
 
+    ///
+    /// 字段名称,原始标识符会去掉`r#`前缀
+    ///
+    pub fn name(&self) -> String {
+        self.ident().unraw().to_string()
+    }
+
     pub fn struct_ident(&self, parent: &Ident) -> Ident {
-        let lit = format!("{}", self.ident());
-        let lit = lit
+        format_ident!("{}{}", parent, self.variant_ident())
+    }
+
+    ///
+    /// 字段枚举的成员名称
+    ///
+    pub fn variant_ident(&self) -> Ident {
+        let lit = self
+            .name()
             .split('_')
+            .filter(|v| !v.is_empty())
             .map(|v| {
-                if v.len() == 1 {
-                    v.to_uppercase()
-                } else {
-                    let (first, other) = v.split_at(1);
-                    format!("{}{}", first.to_uppercase(), other)
+                let mut chars = v.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
                 }
             })
             .collect::<Vec<_>>()
             .join("");
-        format_ident!("{}{}", parent, lit)
+        format_ident!("{}", lit)
     }
 
     pub fn ty(&self) -> &Type {
         &self.ty
     }
 
+    ///
+    /// 是否只读,计算字段也是只读的
+    ///
     pub fn readonly(&self) -> bool {
-        self.readonly
+        self.readonly || self.compute.is_some()
+    }
+
+    pub fn depends_on(&self) -> &[Ident] {
+        match self.depends_on {
+            Some(ref dependencies) => &dependencies.0,
+            None => &[],
+        }
+    }
+
+    pub fn on_dep_change(&self) -> DependencyAction {
+        self.on_dep_change
+            .as_ref()
+            .map(|action| **action)
+            .unwrap_or_default()
+    }
+
+    pub fn compute(&self) -> Option<&syn::Expr> {
+        self.compute.as_ref()
+    }
+
+    ///
+    /// 字段存储类型
+    ///
+    pub fn storage_ty(&self, parent: &Ident) -> TokenStream {
+        let ty = self.ty();
+        let field_struct_ident = self.struct_ident(parent);
+        match self.with {
+            Some(ref with) => quote! { #with<#field_struct_ident, #ty> },
+            None if self.readonly() => {
+                quote! { leptos_controls::SignalField<#field_struct_ident, #ty> }
+            }
+            None => quote! { leptos_controls::RwSignalField<#field_struct_ident, #ty> },
+        }
+    }
+
+    pub fn skip(&self) -> bool {
+        *self.skip
+    }
+
+    ///
+    /// 跳过字段是否使用默认值,而不是保留原始值
+    ///
+    pub fn skip_default(&self) -> Option<TokenStream> {
+        self.default.as_ref().map(|default| match default.as_ref() {
+            Override::Inherit => quote! { Default::default() },
+            Override::Explicit(path) => quote! { #path() },
+        })
     }
 
     pub fn label(&self) -> Cow<'_, str> {
         match self.label {
             Some(ref label) => Cow::from(label),
-            None => {
-                let ident = self.ident();
-                Cow::from(ident.to_string())
-            }
+            None => Cow::from(self.name()),
         }
     }
 
-    pub fn validate(&self) -> Option<&str> {
-        self.validate.as_deref()
+    pub fn validate(&self) -> Option<&syn::Expr> {
+        self.validate.as_ref().map(|validate| &validate.0)
     }
 
     pub fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+
+    pub fn mode(&self) -> Option<ValidationMode> {
+        self.mode
+    }
+
+    pub fn on_change(&self) -> Option<&syn::Expr> {
+        self.on_change.as_ref()
+    }
+
+    pub fn focus_order(&self) -> i32 {
+        self.focus_order.unwrap_or(self.order)
+    }
+
+    pub fn placeholder(&self) -> Option<&str> {
+        self.placeholder.as_deref()
+    }
+
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+
+    pub fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
+    ///
+    /// 所有自定义属性,名称中的`_`转换为`-`
+    ///
+    pub fn attrs(&self) -> impl Iterator<Item = &(String, String)> {
+        self.attr.iter().flat_map(|attrs| attrs.0.iter())
+    }
+
+    ///
+    /// 输入控件类型,未配置时布尔类型为复选框,数字类型为数字输入框,其他为文本输入框
+    ///
+    pub fn widget(&self) -> Widget {
+        self.known_widget().unwrap_or(Widget::Text)
+    }
+
+    ///
+    /// 编译时可以确定的输入控件类型
+    ///
+    /// 其他类型在运行时判断,实现了`SelectOptions`时为下拉选择框,否则为文本输入框。
+    ///
+    pub fn known_widget(&self) -> Option<Widget> {
+        self.widget.or_else(|| self.type_widget())
+    }
+
+    ///
+    /// 根据字段类型推断的输入控件类型,无法在编译时确定时为空
+    ///
+    fn type_widget(&self) -> Option<Widget> {
+        let ident = match self.ty {
+            Type::Path(ref path) if path.qself.is_none() => path.path.get_ident(),
+            _ => None,
+        };
+        match ident.map(|ident| ident.to_string()).as_deref() {
+            Some("bool") => Some(Widget::Checkbox),
+            Some(
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                | "u128" | "usize" | "f32" | "f64",
+            ) => Some(Widget::Number),
+            Some("String" | "char") => Some(Widget::Text),
+            _ if is_string(&self.ty) => Some(Widget::Text),
+            _ => None,
+        }
+    }
+
+    ///
+    /// 类型是否为`Option<String>`,转换为字符串时空字符串对应`None`
+    ///
+    pub fn is_optional_string(&self) -> bool {
+        match self.ty {
+            Type::Path(ref path) => {
+                path.path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "Option")
+                    && is_string(&self.ty)
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// 校验字段配置是否满足
+    ///
+    fn verify(&self) -> Result<(), Error> {
+        if let Some(ref default) = self.default {
+            if !*self.skip {
+                return Err(Error::custom("`default` can only be used with `skip`")
+                    .with_span(&default.span()));
+            }
+        }
+        if let Some(ref action) = self.on_dep_change {
+            if self.depends_on.is_none() {
+                return Err(
+                    Error::custom("`on_dep_change` can only be used with `depends_on`")
+                        .with_span(&action.span()),
+                );
+            }
+        }
+        if let Some(ref with) = self.with {
+            if let Some(name) = Self::conflict(&[
+                ("readonly", self.readonly),
+                ("compute", self.compute.is_some()),
+            ]) {
+                return Err(
+                    Error::custom(format!("`{}` can't be used with `with`", name)).with_span(with),
+                );
+            }
+        }
+        if *self.skip {
+            if let Some(name) = Self::conflict(&[
+                ("readonly", self.readonly),
+                ("with", self.with.is_some()),
+                ("compute", self.compute.is_some()),
+                ("label", self.label.is_some()),
+                ("validate", self.validate.is_some()),
+                ("message", self.message.is_some()),
+                ("mode", self.mode.is_some()),
+                ("on_change", self.on_change.is_some()),
+                ("depends_on", self.depends_on.is_some()),
+                ("widget", self.widget.is_some()),
+                ("placeholder", self.placeholder.is_some()),
+                ("help", self.help.is_some()),
+                ("hidden", self.hidden),
+                ("order", self.order != 0),
+                ("focus_order", self.focus_order.is_some()),
+                ("attr", !self.attr.is_empty()),
+            ]) {
+                return Err(
+                    Error::custom(format!("`skip` field can't be used with `{}`", name))
+                        .with_span(&self.skip.span()),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// 校验字段是否可以使用thaw的输入组件渲染
+    ///
+    /// thaw的数字输入框只支持数字类型,复选框和下拉选择框不支持自定义属性,
+    /// 类型在运行时才能确定输入控件时同样可能渲染为下拉选择框。
+    ///
+    fn verify_thaw_form(&self) -> Result<(), Error> {
+        if self.readonly() {
+            return Ok(());
+        }
+        // thaw的数字输入框只支持数字类型,其他类型在实现`NumberStep`时检查
+        if matches!(self.widget, Some(Widget::Number))
+            && matches!(self.type_widget(), Some(widget) if !matches!(widget, Widget::Number))
+        {
+            return Err(Error::custom(format!(
+                "`widget = \"number\"` is rendered by the thaw `InputNumber` which requires a numeric type, found `{}`",
+                self.ty.to_token_stream()
+            ))
+            .with_span(&self.ty));
+        }
+        let Some(attr) = self.attr.first() else {
+            return Ok(());
+        };
+        let message = match self.known_widget() {
+            Some(Widget::Checkbox) => {
+                String::from("`attr` is not supported by the thaw `Checkbox`, render the field with a `FieldSlot`")
+            }
+            Some(Widget::Select) => {
+                String::from("`attr` is not supported by the thaw `Select`, render the field with a `FieldSlot`")
+            }
+            Some(_) => return Ok(()),
+            None => format!(
+                "`attr` requires an explicit `widget` in `thaw_form`, `{}` is rendered as a thaw `Select` when it implements `SelectOptions`",
+                self.ty.to_token_stream()
+            ),
+        };
+        Err(Error::custom(message).with_span(&attr.span()))
+    }
+
+    ///
+    /// 获取第一个冲突的配置名称
+    ///
+    fn conflict(options: &[(&'static str, bool)]) -> Option<&'static str> {
+        options
+            .iter()
+            .find(|(_, exists)| *exists)
+            .map(|(name, _)| *name)
+    }
+}
+
+///
+/// 校验模式
+///
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub enum ValidationMode {
+    OnChange,
+    OnBlur,
+    OnSubmit,
+    OnTouched,
+}
+
+impl ToTokens for ValidationMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            ValidationMode::OnChange => format_ident!("OnChange"),
+            ValidationMode::OnBlur => format_ident!("OnBlur"),
+            ValidationMode::OnSubmit => format_ident!("OnSubmit"),
+            ValidationMode::OnTouched => format_ident!("OnTouched"),
+        };
+        tokens.extend(quote! { leptos_controls::ValidationMode::#variant });
+    }
+}
+
+///
+/// 输入控件类型
+///
+#[derive(Clone, Copy, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub enum Widget {
+    Text,
+    Password,
+    Number,
+    Checkbox,
+    Select,
+    #[darling(rename = "textarea")]
+    TextArea,
+}
+
+impl ToTokens for Widget {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            Widget::Text => format_ident!("Text"),
+            Widget::Password => format_ident!("Password"),
+            Widget::Number => format_ident!("Number"),
+            Widget::Checkbox => format_ident!("Checkbox"),
+            Widget::Select => format_ident!("Select"),
+            Widget::TextArea => format_ident!("TextArea"),
+        };
+        tokens.extend(quote! { leptos_controls::Widget::#variant });
+    }
+}
+
+///
+/// 控制器的存储方式
+///
+#[derive(Clone, Copy, Default, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub enum Storage {
+    ///
+    /// 每个字段使用独立的信号
+    ///
+    #[default]
+    Field,
+
+    ///
+    /// 整个结构体使用单个信号,字段为信号的切片,并且支持通过`bind`绑定外部信号
+    ///
+    Single,
+}
+
+///
+/// 字段的校验方法,支持`validate = is_valid`、`validate = "is_valid"`和闭包表达式
+///
+pub struct ValidateFn(syn::Expr);
+
+impl ValidateFn {
+    fn error() -> Error {
+        Error::custom(
+            "expected a function path or closure, e.g. `validate = is_valid` or `validate = |value: &T| ...`",
+        )
+    }
+}
+
+impl FromMeta for ValidateFn {
+    fn from_value(value: &Lit) -> darling::Result<Self> {
+        match value {
+            Lit::Str(lit) => lit
+                .parse::<Expr>()
+                .map(ValidateFn)
+                .map_err(|_| Self::error().with_span(lit)),
+            _ => Err(Self::error().with_span(value)),
+        }
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            expr => Ok(ValidateFn(expr.clone())),
+        }
+    }
+}
+
+///
+/// 依赖的字段集合,支持`depends_on = "a, b"`和`depends_on(a, b)`两种格式
+///
+pub struct Dependencies(Vec<Ident>);
+
+impl FromMeta for Dependencies {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(Meta::Path(path)) => path
+                    .get_ident()
+                    .cloned()
+                    .ok_or_else(|| Error::custom("expected field name").with_span(path)),
+                _ => Err(Error::custom("expected field name").with_span(item)),
+            })
+            .collect::<darling::Result<Vec<_>>>()
+            .map(Dependencies)
+    }
+
+    fn from_value(value: &Lit) -> darling::Result<Self> {
+        match value {
+            Lit::Str(lit) => lit
+                .value()
+                .split(',')
+                .map(|name| {
+                    syn::parse_str::<Ident>(name.trim())
+                        .map(|ident| Ident::new(&ident.to_string(), lit.span()))
+                        .map_err(|_| {
+                            Error::custom(format!("invalid field name `{}`", name.trim()))
+                                .with_span(lit)
+                        })
+                })
+                .collect::<darling::Result<Vec<_>>>()
+                .map(Dependencies),
+            _ => Err(Error::unexpected_lit_type(value)),
+        }
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        match expr {
+            Expr::Path(path) => path
+                .path
+                .get_ident()
+                .cloned()
+                .map(|ident| Dependencies(vec![ident]))
+                .ok_or_else(|| Error::custom("expected field name").with_span(path)),
+            Expr::Lit(lit) => Self::from_value(&lit.lit),
+            Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(Error::unexpected_expr_type(expr).with_span(expr)),
+        }
+    }
+}
+
+///
+/// 自定义属性,格式为`attr(autocomplete = "off", data_test = "name")`
+///
+pub struct Attrs(Vec<(String, String)>);
+
+impl FromMeta for Attrs {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                NestedMeta::Meta(Meta::NameValue(meta)) => {
+                    let name = meta
+                        .path
+                        .get_ident()
+                        .ok_or_else(|| {
+                            Error::custom("expected attribute name").with_span(&meta.path)
+                        })?
+                        .unraw()
+                        .to_string()
+                        .replace('_', "-");
+                    if !is_attribute_name(&name) {
+                        return Err(Error::custom(format!(
+                            "invalid attribute name `{}`, expected letters and digits separated by `_`",
+                            name
+                        ))
+                        .with_span(&meta.path));
+                    }
+                    match &meta.value {
+                        Expr::Lit(syn::ExprLit {
+                            lit: Lit::Str(value),
+                            ..
+                        }) => Ok((name, value.value())),
+                        value => Err(Error::custom("expected string value").with_span(value)),
+                    }
+                }
+                _ => Err(Error::custom("expected `name = \"value\"`").with_span(item)),
+            })
+            .collect::<darling::Result<Vec<_>>>()
+            .map(Attrs)
+    }
+}
+
+///
+/// 类型是否为`String`或者`Option<String>`,可以直接转换为文本输入框的`Model`
+///
+pub fn is_string(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+    match segment.arguments {
+        PathArguments::None => segment.ident == "String",
+        PathArguments::AngleBracketed(ref arguments) if segment.ident == "Option" => {
+            match arguments.args.first() {
+                Some(GenericArgument::Type(ty)) if arguments.args.len() == 1 => is_string(ty),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+///
+/// 属性名称是否可以作为`attr:name`使用: 以`-`分隔的多段,每段以字母开头并且只包含字母和数字
+///
+fn is_attribute_name(name: &str) -> bool {
+    name.split('-').all(|segment| {
+        let mut chars = segment.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+///
+/// 依赖的字段变更时的处理方式
+///
+#[derive(Clone, Copy, Default, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub enum DependencyAction {
+    ///
+    /// 重置为默认值
+    ///
+    Reset,
+
+    ///
+    /// 重新校验
+    ///
+    #[default]
+    Revalidate,
 }
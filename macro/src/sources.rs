@@ -0,0 +1,67 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::options::ControlOptions;
+
+pub struct SourcesStruct<'a> {
+    options: &'a ControlOptions,
+}
+
+impl<'a> SourcesStruct<'a> {
+    pub fn new(options: &'a ControlOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl<'a> From<SourcesStruct<'a>> for TokenStream {
+    fn from(value: SourcesStruct<'a>) -> Self {
+        let options = value.options;
+        if options.source_fields().next().is_none() {
+            return quote! {};
+        }
+        let ident = options.ident();
+        let vis = options.vis();
+        let sources_struct_ident = options.sources_struct_ident();
+        let struct_doc = format!("External signals for the readonly fields of `{}`", ident);
+
+        // 只读字段的外部信号
+        let field_with_type_tokens = options.source_fields().map(|field| {
+            let vis = field.vis();
+            let ty = field.ty();
+            let field_ident = field.ident();
+            quote! {
+                #vis #field_ident: Option<leptos::Signal<#ty>>
+            }
+        });
+
+        // 绑定外部信号
+        let fn_source_tokens = options.source_fields().map(|field| {
+            let vis = field.vis();
+            let ty = field.ty();
+            let field_ident = field.ident();
+            let doc = format!(
+                "Bind the readonly field `{}` to an external signal",
+                field_ident
+            );
+            quote! {
+                #[doc = #doc]
+                #vis fn #field_ident(mut self, source: impl Into<leptos::Signal<#ty>>) -> Self {
+                    self.#field_ident = Some(source.into());
+                    self
+                }
+            }
+        });
+
+        quote! {
+            #[doc = #struct_doc]
+            #[derive(Clone, Copy, Default)]
+            #vis struct #sources_struct_ident {
+                #(#field_with_type_tokens,)*
+            }
+
+            impl #sources_struct_ident {
+                #(#fn_source_tokens)*
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use crate::options::{is_string, ControlFieldOptions, ControlOptions, Widget};
+
+pub struct ThawForm<'a> {
+    options: &'a ControlOptions,
+}
+
+impl<'a> ThawForm<'a> {
+    pub fn new(options: &'a ControlOptions) -> Self {
+        Self { options }
+    }
+}
+
+///
+/// 字段的thaw输入组件
+///
+fn input_tokens(field: &ControlFieldOptions) -> TokenStream {
+    let ty = field.ty();
+    let field_ident = field.ident();
+    // 输入提示和自定义属性,只有文本和数字输入框支持
+    let placeholder = field
+        .placeholder()
+        .map(|placeholder| quote! { placeholder=#placeholder });
+    let attrs = field
+        .attrs()
+        .map(|(name, value)| {
+            // 属性名称已经校验,每段都是合法的标识符
+            let segments = name.split('-').map(|segment| format_ident!("{}", segment));
+            quote! { attr:#(#segments)-* = #value }
+        })
+        .collect::<Vec<_>>();
+    if field.readonly() {
+        return quote! {
+            <Input
+                value=leptos_controls::string_model(controls.#field_ident)
+                disabled=true
+                attr:id=ids.input()
+                #placeholder
+                #(#attrs)*
+            />
+        };
+    }
+    // 其他类型的文本输入通过字符串转换读写
+    let text_model = if is_string(ty) {
+        quote! { controls.#field_ident }
+    } else {
+        quote! { leptos_controls::string_model(controls.#field_ident) }
+    };
+    let text_input = quote! {
+        <Input
+            value=#text_model
+            invalid=leptos_controls::field_invalid(controls.#field_ident)
+            attr:id=ids.input()
+            #placeholder
+            #(#attrs)*
+        />
+    };
+    // 步长的类型错误指向字段类型,并提示需要实现`NumberStep`
+    let step = quote_spanned! {ty.span()=> {<#ty as leptos_controls::NumberStep>::step()} };
+    let Some(widget) = field.known_widget() else {
+        // 实现了`SelectOptions`的类型在运行时选择下拉选择框,和`AutoForm`保持一致
+        return quote! {
+            {
+                #[allow(unused_imports)]
+                use leptos_controls::{NoSelectInput, ViaSelectInput};
+                (&leptos_controls::SelectInput(controls.#field_ident, std::marker::PhantomData::<#ty>))
+                    .select_input(|| leptos::view! { #text_input }.into_view())
+            }
+        };
+    };
+    match widget {
+        Widget::Text => text_input,
+        Widget::Password => quote! {
+            <Input
+                value=#text_model
+                variant=InputVariant::Password
+                invalid=leptos_controls::field_invalid(controls.#field_ident)
+                attr:id=ids.input()
+                #placeholder
+                #(#attrs)*
+            />
+        },
+        Widget::TextArea => quote! {
+            <TextArea
+                value=#text_model
+                invalid=leptos_controls::field_invalid(controls.#field_ident)
+                attr:id=ids.input()
+                #placeholder
+                #(#attrs)*
+            />
+        },
+        Widget::Number => quote! {
+            <InputNumber
+                value=controls.#field_ident
+                step=#step
+                invalid=leptos_controls::field_invalid(controls.#field_ident)
+                attr:id=ids.input()
+                #placeholder
+                #(#attrs)*
+            />
+        },
+        Widget::Checkbox => quote! {
+            <Checkbox value=controls.#field_ident />
+        },
+        Widget::Select => quote! {
+            <Select
+                value=controls.#field_ident
+                options={leptos_controls::select_options::<#ty>()}
+            />
+        },
+    }
+}
+
+impl<'a> From<ThawForm<'a>> for TokenStream {
+    fn from(value: ThawForm<'a>) -> Self {
+        let options = value.options;
+        if !options.thaw_form() {
+            return quote! {};
+        }
+        let ident = options.ident();
+        let vis = options.vis();
+        let control_struct_ident = options.control_struct_ident();
+        let form_ident = options.form_ident();
+        let controls_id = options.id();
+        let form_doc = format!(
+            "Form component of `{}` which lays out thaw inputs for each field, element ids are prefixed with `id` (default `{}`)",
+            ident, controls_id
+        );
+
+        // 按照顺序渲染,顺序相同时按照声明顺序
+        let mut fields = options.control_fields().collect::<Vec<_>>();
+        fields.sort_by_key(|field| field.order());
+
+        // 每个字段的渲染,存在同名的插槽时使用插槽,隐藏的字段只渲染插槽
+        let field_view_idents = fields
+            .iter()
+            .map(|field| format_ident!("{}_view", field.ident()))
+            .collect::<Vec<_>>();
+        let field_view_tokens = fields.iter().map(|field| {
+            let field_ident = field.ident();
+            let name = field.name();
+            let view_ident = format_ident!("{}_view", field_ident);
+            let fallback = if field.hidden() {
+                quote! { leptos::View::default() }
+            } else {
+                let input = input_tokens(field);
+                // thaw的复选框和下拉选择框不支持设置输入元素的id
+                let ty = field.ty();
+                let labelable = match field.known_widget() {
+                    _ if field.readonly() => quote! { true },
+                    Some(Widget::Checkbox | Widget::Select) => quote! { false },
+                    Some(_) => quote! { true },
+                    None => quote! {
+                        {
+                            #[allow(unused_imports)]
+                            use leptos_controls::{NoSelectInput, ViaSelectInput};
+                            !(&leptos_controls::SelectInput(controls.#field_ident, std::marker::PhantomData::<#ty>)).is_select()
+                        }
+                    },
+                };
+                quote! {
+                    {
+                        let ids = leptos_controls::FieldIds::new(&prefix, #name);
+                        leptos::view! {
+                            <FieldLabel field=controls.#field_ident ids=ids.clone() labelable=#labelable>
+                                #input
+                            </FieldLabel>
+                        }
+                        .into_view()
+                    }
+                }
+            };
+            quote! {
+                let #view_ident = match field_slot.iter().find(|slot| slot.name() == #name) {
+                    Some(slot) => slot.render(),
+                    None => #fallback,
+                };
+            }
+        });
+
+        quote! {
+            #[doc = #form_doc]
+            #[leptos::component]
+            #vis fn #form_ident(
+                controls: #control_struct_ident,
+                #[prop(optional)] field_slot: Vec<leptos_controls::FieldSlot>,
+                #[prop(optional)] footer_slot: Option<leptos_controls::FooterSlot>,
+                #[prop(optional, into)] on_submit: Option<leptos::Callback<#ident>>,
+                #[prop(optional, into)] id: Option<String>,
+            ) -> impl leptos::IntoView {
+                #[allow(unused_imports)]
+                use leptos::IntoView;
+                #[allow(unused_imports)]
+                use leptos_controls::__thaw::{Checkbox, Input, InputNumber, InputVariant, Select, TextArea};
+                use leptos_controls::{FieldLabel, FormControls, SubmitBar};
+
+                let prefix = id.unwrap_or_else(|| String::from(#controls_id));
+                #(#field_view_tokens)*
+                let footer = match footer_slot {
+                    Some(footer) => footer.render(),
+                    None => {
+                        let on_submit = on_submit.unwrap_or_else(|| leptos::Callback::new(|_| {}));
+                        leptos::view! { <SubmitBar controls on_submit /> }.into_view()
+                    }
+                };
+                leptos::view! {
+                    <FormControls controls>
+                        #({#field_view_idents})*
+                        {footer}
+                    </FormControls>
+                }
+            }
+        }
+    }
+}
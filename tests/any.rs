@@ -0,0 +1,99 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Tags(Vec<String>);
+
+#[derive(Clone, Default, Controls)]
+struct Product {
+    name: String,
+    price: u32,
+    on_sale: bool,
+    tags: Tags,
+    #[field(readonly)]
+    code: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(storage = "single")]
+struct ProductSingle {
+    price: u32,
+}
+
+#[test]
+fn values_convert_from_and_to_strings() {
+    let runtime = create_runtime();
+    let controls = ProductControls::new(Product::default());
+    let price = controls.field(ProductField::Price);
+    price.set_value_string("42").unwrap();
+    assert_eq!(controls.price.get_untracked(), 42);
+    assert_eq!(price.value_string().as_deref(), Some("42"));
+
+    let on_sale = controls.field(ProductField::OnSale);
+    on_sale.set_value_string("true").unwrap();
+    assert!(controls.on_sale.get_untracked());
+
+    // 解析失败时保留原来的值
+    assert!(price.set_value_string("abc").is_err());
+    assert_eq!(controls.price.get_untracked(), 42);
+    runtime.dispose();
+}
+
+#[test]
+fn unsupported_and_readonly_fields_reject_strings() {
+    let runtime = create_runtime();
+    let controls = ProductControls::new(Product::default());
+    let tags = controls.field(ProductField::Tags);
+    assert_eq!(tags.value_string(), None);
+    assert!(tags.set_value_string("a,b").is_err());
+
+    let code = controls.field(ProductField::Code);
+    assert!(code.readonly());
+    assert!(!tags.readonly());
+    assert!(code.set_value_string("p-1").is_err());
+    assert_eq!(code.value_string().as_deref(), Some(""));
+    runtime.dispose();
+}
+
+#[test]
+fn reset_clears_value_and_state() {
+    let runtime = create_runtime();
+    let controls = ProductControls::new(Product::default());
+    let name = controls.field(ProductField::Name);
+    name.set_value_string("leptos").unwrap();
+    name.touch();
+    assert!(name.is_dirty());
+    assert!(name.touched());
+
+    name.reset();
+    assert_eq!(controls.name.get_untracked(), "");
+    assert!(!name.is_dirty());
+    assert!(!name.touched());
+    runtime.dispose();
+}
+
+#[test]
+fn fields_downcast_to_storage() {
+    let runtime = create_runtime();
+    let controls = ProductControls::new(Product::default());
+    let price = controls.field(ProductField::Price).as_any();
+    assert!(price.is::<RwSignalField<ProductPrice, u32>>());
+    assert!(controls
+        .field(ProductField::Code)
+        .as_any()
+        .is::<SignalField<ProductCode, String>>());
+
+    let controls = ProductSingleControls::new(ProductSingle::default());
+    let price = controls
+        .get_field("price")
+        .and_then(|field| {
+            field
+                .as_any()
+                .downcast_ref::<SliceField<ProductSinglePrice, ProductSingle, u32>>()
+        })
+        .copied()
+        .unwrap();
+    price.set(1);
+    assert_eq!(controls.snapshot().price, 1);
+    runtime.dispose();
+}
@@ -0,0 +1,228 @@
+#![cfg(feature = "view")]
+
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+struct UserProfile {
+    #[field(label = "名称", help = "真实姓名")]
+    name: String,
+    enabled: bool,
+}
+
+fn render(id: Option<&str>) -> String {
+    let controls = UserProfileControls::new(UserProfile::default());
+    let view = match id {
+        Some(id) => view! { <AutoForm controls id /> }.into_view(),
+        None => view! { <AutoForm controls /> }.into_view(),
+    };
+    view.render_to_string().to_string()
+}
+
+///
+/// 渲染结果中所有`id`和`for`属性的值
+///
+fn ids(html: &str) -> Vec<&str> {
+    html.split(['"', ' '])
+        .zip(html.split(['"', ' ']).skip(1))
+        .filter(|&(name, _)| name == "id=" || name == "for=")
+        .map(|(_, value)| value)
+        .collect()
+}
+
+#[test]
+fn auto_form_ids_derive_from_controls_and_field_names() {
+    let runtime = create_runtime();
+    let html = render(None);
+    assert!(html.contains(r#"id="user-profile-name""#), "{html}");
+    assert!(html.contains(r#"for="user-profile-name""#), "{html}");
+    assert!(html.contains(r#"id="user-profile-name-help""#), "{html}");
+    assert!(html.contains(r#"id="user-profile-enabled""#), "{html}");
+    assert!(html.contains(r#"for="user-profile-enabled""#), "{html}");
+
+    // 多次渲染得到相同的id,与水合键无关
+    assert_eq!(ids(&render(None)), ids(&html));
+    assert_eq!(ids(&html).len(), 9);
+    runtime.dispose();
+}
+
+#[test]
+fn auto_form_id_overrides_prefix() {
+    let runtime = create_runtime();
+    let html = render(Some("editor"));
+    assert!(html.contains(r#"id="editor-name""#), "{html}");
+    assert!(html.contains(r#"for="editor-name""#), "{html}");
+    assert!(!html.contains("user-profile-"), "{html}");
+    runtime.dispose();
+}
+
+#[derive(Clone, Default, Controls)]
+struct Account {
+    #[field(order = 1, placeholder = "请输入账号", attr(autocomplete = "username"))]
+    name: String,
+    mobile: String,
+    #[field(hidden)]
+    token: String,
+}
+
+#[test]
+fn auto_form_renders_field_metadata() {
+    let runtime = create_runtime();
+    let controls = AccountControls::new(Account::default());
+    let html = view! { <AutoForm controls /> }
+        .into_view()
+        .render_to_string()
+        .to_string();
+    assert!(html.contains(r#"placeholder="请输入账号""#), "{html}");
+    assert!(html.contains(r#"autocomplete="username""#), "{html}");
+    // 隐藏的字段不渲染,顺序小的字段先渲染,顺序相同时按照声明顺序
+    assert!(!html.contains("account-token"), "{html}");
+    let mobile = html.find(r#"id="account-mobile""#).unwrap();
+    let name = html.find(r#"id="account-name""#).unwrap();
+    assert!(mobile < name, "{html}");
+    runtime.dispose();
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum Level {
+    #[default]
+    Low,
+    High,
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Low => f.write_str("low"),
+            Level::High => f.write_str("high"),
+        }
+    }
+}
+
+impl std::str::FromStr for Level {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "low" => Ok(Level::Low),
+            "high" => Ok(Level::High),
+            _ => Err(format!("unknown level `{}`", value)),
+        }
+    }
+}
+
+impl SelectOptions for Level {
+    fn options() -> Vec<Self> {
+        vec![Level::Low, Level::High]
+    }
+}
+
+#[derive(Clone, Default, Controls)]
+struct Task {
+    estimate: u32,
+    done: bool,
+    level: Level,
+    nickname: Option<String>,
+    tags: Vec<String>,
+}
+
+fn task() -> Task {
+    Task {
+        estimate: 3,
+        done: true,
+        level: Level::High,
+        nickname: Some(String::from("leptos")),
+        tags: vec![String::from("rust")],
+    }
+}
+
+///
+/// 渲染结果中指定id的元素的开始标签
+///
+fn element<'a>(html: &'a str, id: &str) -> &'a str {
+    let position = html
+        .find(&format!(r#"id="{id}""#))
+        .unwrap_or_else(|| panic!("`{id}` in {html}"));
+    let start = html[..position].rfind('<').unwrap();
+    let end = position + html[position..].find('>').unwrap();
+    &html[start..=end]
+}
+
+#[test]
+fn auto_form_infers_widgets_from_field_types() {
+    let runtime = create_runtime();
+    let controls = TaskControls::new(task());
+    let html = view! { <AutoForm controls /> }
+        .into_view()
+        .render_to_string()
+        .to_string();
+
+    let estimate = element(&html, "task-estimate");
+    assert!(estimate.contains(r#"type="number""#), "{estimate}");
+    assert!(estimate.contains(r#"value="3""#), "{estimate}");
+
+    let done = element(&html, "task-done");
+    assert!(done.contains(r#"type="checkbox""#), "{done}");
+    assert!(done.contains("checked"), "{done}");
+
+    let level = element(&html, "task-level");
+    assert!(level.starts_with("<select"), "{level}");
+    assert!(html.contains(r#"<option value="low""#), "{html}");
+    assert!(html.contains(r#"<option value="high" selected"#), "{html}");
+
+    // `Option<String>`作为文本输入,不支持字符串转换的类型禁用输入
+    let nickname = element(&html, "task-nickname");
+    assert!(nickname.contains(r#"type="text""#), "{nickname}");
+    assert!(nickname.contains(r#"value="leptos""#), "{nickname}");
+    assert!(!nickname.contains("disabled"), "{nickname}");
+    let tags = element(&html, "task-tags");
+    assert!(tags.contains("disabled"), "{tags}");
+    runtime.dispose();
+}
+
+#[test]
+fn optional_string_fields_convert_empty_strings_to_none() {
+    let runtime = create_runtime();
+    let controls = TaskControls::new(Task::default());
+    let nickname = controls.get_field("nickname").unwrap();
+    assert_eq!(nickname.value_string().as_deref(), Some(""));
+    nickname.set_value_string("leptos").unwrap();
+    assert_eq!(controls.nickname.get_untracked().as_deref(), Some("leptos"));
+    nickname.set_value_string("").unwrap();
+    assert_eq!(controls.nickname.get_untracked(), None);
+    runtime.dispose();
+}
+
+#[test]
+fn auto_form_slots_replace_fields_and_footer() {
+    let runtime = create_runtime();
+    let controls = TaskControls::new(task());
+    let html = view! {
+        <AutoForm controls submit_label="保存">
+            <FieldSlot slot name="tags">
+                <span id="custom-tags">"自定义"</span>
+            </FieldSlot>
+        </AutoForm>
+    }
+    .into_view()
+    .render_to_string()
+    .to_string();
+    assert!(html.contains("custom-tags"), "{html}");
+    assert!(!html.contains(r#"id="task-tags""#), "{html}");
+    assert!(html.contains(r#"<button type="submit""#), "{html}");
+    assert!(html.contains("保存"), "{html}");
+
+    let html = view! {
+        <AutoForm controls submit_label="保存">
+            <FooterSlot slot>
+                <button type="button" id="custom-footer">"下一步"</button>
+            </FooterSlot>
+        </AutoForm>
+    }
+    .into_view()
+    .render_to_string()
+    .to_string();
+    assert!(html.contains("custom-footer"), "{html}");
+    assert!(!html.contains("保存"), "{html}");
+    runtime.dispose();
+}
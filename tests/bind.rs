@@ -0,0 +1,246 @@
+mod common;
+
+use common::Wrapped;
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+struct Profile {
+    name: String,
+    age: u32,
+    #[field(readonly)]
+    id: u64,
+    #[field(with = Wrapped)]
+    note: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+#[controls(storage = "single")]
+struct ProfileSingle {
+    name: String,
+    age: u32,
+    #[field(readonly)]
+    id: u64,
+}
+
+thread_local! {
+    static CHANGES: std::cell::RefCell<Vec<(String, Option<String>)>> = Default::default();
+}
+
+#[allow(clippy::ptr_arg)]
+fn record_change(new: &String, old: Option<&String>) {
+    CHANGES.with_borrow_mut(|changes| changes.push((new.clone(), old.cloned())));
+}
+
+fn take_changes() -> Vec<(String, Option<String>)> {
+    CHANGES.take()
+}
+
+// 依赖字段声明在被依赖的字段之前
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+struct Address {
+    #[field(depends_on = country, on_dep_change = "reset", on_change = record_change)]
+    province: String,
+    country: String,
+    street: String,
+    #[field(skip)]
+    zip: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+#[controls(storage = "single")]
+struct AddressSingle {
+    #[field(depends_on = country, on_dep_change = "reset", on_change = record_change)]
+    province: String,
+    country: String,
+    street: String,
+}
+
+// 没有实现Clone的结构体同样可以派生,只是不能绑定外部信号
+#[derive(Debug, Default, Controls)]
+#[allow(dead_code)]
+struct Opaque {
+    name: String,
+}
+
+fn profile() -> Profile {
+    Profile {
+        name: String::from("leptos"),
+        age: 18,
+        id: 1,
+        note: String::from("note"),
+    }
+}
+
+#[test]
+fn bound_fields_read_initial_value() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(profile());
+    let controls = ProfileControls::bind(source);
+    assert_eq!(controls.snapshot(), profile());
+    assert!(!controls.name.is_dirty());
+    runtime.dispose();
+}
+
+#[test]
+fn writes_through_controls_update_source() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(profile());
+    let controls = ProfileControls::bind(source);
+    controls.name.set(String::from("controls"));
+    controls.age.update(|age| *age += 1);
+    assert_eq!(
+        source.get_untracked(),
+        Profile {
+            name: String::from("controls"),
+            age: 19,
+            ..profile()
+        }
+    );
+    assert!(controls.name.is_dirty());
+
+    // 自定义存储的字段只读取初始值
+    controls.note.set(String::from("changed"));
+    assert_eq!(source.get_untracked().note, "note");
+    runtime.dispose();
+}
+
+#[test]
+fn writes_through_source_update_controls() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(profile());
+    let controls = ProfileControls::bind(source);
+    let names = store_value(Vec::new());
+    let stop = controls
+        .name
+        .watch(move |name, _| names.update_value(|names| names.push(name.clone())));
+
+    source.update(|profile| {
+        profile.name = String::from("source");
+        profile.id = 2;
+    });
+    assert_eq!(controls.name.get_untracked(), "source");
+    assert_eq!(controls.id.get_untracked(), 2);
+    assert_eq!(names.get_value(), vec![String::from("source")]);
+
+    // 外部的修改不会标记为已变更
+    assert!(!controls.name.is_dirty());
+    stop();
+    runtime.dispose();
+}
+
+#[test]
+fn single_storage_binds_in_both_directions() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(ProfileSingle::default());
+    let controls = ProfileSingleControls::bind(source);
+    controls.name.set(String::from("controls"));
+    assert_eq!(source.get_untracked().name, "controls");
+
+    source.update(|profile| {
+        profile.age = 20;
+        profile.id = 3;
+    });
+    assert_eq!(controls.age.get_untracked(), 20);
+    assert_eq!(controls.id.get_untracked(), 3);
+    runtime.dispose();
+}
+
+#[test]
+fn bound_controls_stop_syncing_after_dispose() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(profile());
+    let controls = ProfileControls::bind(source);
+    controls.dispose();
+    source.update(|profile| profile.name = String::from("source"));
+    assert_eq!(controls.name.try_get_untracked(), None);
+    assert_eq!(source.get_untracked().name, "source");
+    runtime.dispose();
+}
+
+fn address() -> Address {
+    Address {
+        province: String::from("on"),
+        country: String::from("ca"),
+        street: String::from("main"),
+        zip: String::from("000"),
+    }
+}
+
+#[test]
+fn source_changes_skip_dependency_resets() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(address());
+    let controls = AddressControls::bind(source);
+    take_changes();
+
+    // 同一次修改中同时写入依赖字段和被依赖的字段
+    source.update(|address| {
+        address.country = String::from("us");
+        address.province = String::from("ny");
+    });
+    assert_eq!(controls.province.get_untracked(), "ny");
+    assert_eq!(controls.country.get_untracked(), "us");
+    assert_eq!(source.get_untracked().province, "ny");
+    assert_eq!(take_changes(), vec![]);
+
+    // 修改无关字段时不会同步未变化的字段
+    source.update(|address| address.street = String::from("broadway"));
+    assert_eq!(controls.province.get_untracked(), "ny");
+    assert_eq!(source.get_untracked().province, "ny");
+    assert_eq!(take_changes(), vec![]);
+
+    // 通过控制器修改时依然会重置依赖字段并写回外部信号
+    controls.country.set(String::from("ca"));
+    assert_eq!(controls.province.get_untracked(), "");
+    assert_eq!(source.get_untracked().province, "");
+    assert_eq!(
+        take_changes(),
+        vec![(String::new(), Some(String::from("ny")))]
+    );
+    runtime.dispose();
+}
+
+#[test]
+fn skipped_fields_follow_source() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(address());
+    let controls = AddressControls::bind(source);
+    source.update(|address| address.zip = String::from("100"));
+    assert_eq!(controls.snapshot().zip, "100");
+    runtime.dispose();
+}
+
+#[test]
+fn single_storage_source_changes_skip_dependency_resets() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(AddressSingle {
+        province: String::from("on"),
+        country: String::from("ca"),
+        street: String::from("main"),
+    });
+    let controls = AddressSingleControls::bind(source);
+    take_changes();
+
+    // 修改无关字段时依赖字段和外部信号都不变
+    source.update(|address| address.street = String::from("broadway"));
+    assert_eq!(controls.province.get_untracked(), "on");
+    assert_eq!(source.get_untracked().province, "on");
+    assert_eq!(take_changes(), vec![]);
+
+    source.update(|address| {
+        address.country = String::from("us");
+        address.province = String::from("ny");
+    });
+    assert_eq!(controls.province.get_untracked(), "ny");
+    assert_eq!(source.get_untracked().province, "ny");
+    assert_eq!(take_changes(), vec![]);
+
+    controls.country.set(String::from("ca"));
+    assert_eq!(source.get_untracked().province, "");
+    assert_eq!(
+        take_changes(),
+        vec![(String::new(), Some(String::from("ny")))]
+    );
+    runtime.dispose();
+}
@@ -0,0 +1,106 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+struct Settings {
+    #[field(validate = |name: &String| !name.is_empty())]
+    name: String,
+    age: u32,
+    ratio: f64,
+    enabled: bool,
+}
+
+fn render(binding: FieldBinding) -> String {
+    view! { <input {..binding.attrs()} use:bind=binding /> }
+        .into_view()
+        .render_to_string()
+        .to_string()
+}
+
+#[test]
+fn attrs_render_value_on_server() {
+    let runtime = create_runtime();
+    let controls = SettingsControls::new(Settings {
+        name: String::from("leptos"),
+        age: 18,
+        ratio: 0.5,
+        enabled: true,
+    });
+    let name = render(controls.name.bind_text());
+    assert!(name.contains(r#"value="leptos""#));
+    assert!(name.contains(r#"aria-required="true""#));
+    assert!(name.contains(r#"aria-invalid="false""#));
+    assert!(render(controls.age.bind_number()).contains(r#"value="18""#));
+    assert!(render(controls.ratio.bind_select()).contains(r#"value="0.5""#));
+    assert!(render(controls.enabled.bind_checked()).contains("checked"));
+
+    controls.enabled.set(false);
+    assert!(!render(controls.enabled.bind_checked()).contains("checked"));
+    runtime.dispose();
+}
+
+#[test]
+fn text_input_keeps_raw_value() {
+    let runtime = create_runtime();
+    let controls = SettingsControls::new(Settings::default());
+    let binding = controls.name.bind_text();
+    binding.input(" 1e2 ").unwrap();
+    assert_eq!(controls.name.get_untracked(), " 1e2 ");
+    runtime.dispose();
+}
+
+#[test]
+fn number_input_parses_numbers() {
+    let runtime = create_runtime();
+    let controls = SettingsControls::new(Settings::default());
+    let age = controls.age.bind_number();
+    age.input(" 20 ").unwrap();
+    assert_eq!(controls.age.get_untracked(), 20);
+    // 整数字段接收小数部分为零的值和科学计数法
+    age.input("21.0").unwrap();
+    assert_eq!(controls.age.get_untracked(), 21);
+    age.input("1e2").unwrap();
+    assert_eq!(controls.age.get_untracked(), 100);
+
+    let ratio = controls.ratio.bind_number();
+    ratio.input("0.25").unwrap();
+    assert_eq!(controls.ratio.get_untracked(), 0.25);
+    runtime.dispose();
+}
+
+#[test]
+fn number_input_rejects_invalid_values() {
+    let runtime = create_runtime();
+    let controls = SettingsControls::new(Settings {
+        age: 18,
+        ratio: 0.5,
+        ..Default::default()
+    });
+    let age = controls.age.bind_number();
+    for value in ["", "abc", "NaN", "inf", "1.5", "-1"] {
+        assert!(age.input(value).is_err(), "{value}");
+        assert_eq!(controls.age.get_untracked(), 18);
+    }
+
+    let ratio = controls.ratio.bind_number();
+    for value in ["", "NaN", "-inf", "1e400"] {
+        assert!(ratio.input(value).is_err(), "{value}");
+        assert_eq!(controls.ratio.get_untracked(), 0.5);
+    }
+    // 文本绑定不做数字校验,由字段类型解析
+    controls.ratio.bind_text().input("NaN").unwrap();
+    assert!(controls.ratio.get_untracked().is_nan());
+    runtime.dispose();
+}
+
+#[test]
+fn checked_input_sets_bool() {
+    let runtime = create_runtime();
+    let controls = SettingsControls::new(Settings::default());
+    let binding = controls.enabled.bind_checked();
+    binding.input("true").unwrap();
+    assert!(controls.enabled.get_untracked());
+    binding.input("false").unwrap();
+    assert!(!controls.enabled.get_untracked());
+    runtime.dispose();
+}
@@ -0,0 +1,152 @@
+#![allow(dead_code)]
+
+use leptos::*;
+use leptos_controls::*;
+use std::any::Any;
+use std::borrow::Cow;
+
+///
+/// 在当前所有者的子所有者中执行,返回结果和子所有者的销毁器,模拟子组件的挂载和卸载
+///
+pub fn child<T: 'static>(f: impl FnOnce() -> T + 'static) -> (T, Disposer) {
+    as_child_of_current_owner(|f: Box<dyn FnOnce() -> T>| f())(Box::new(f))
+}
+
+///
+/// 自定义字段存储,委托给[`RwSignalField`]
+///
+pub struct Wrapped<M, T>(RwSignalField<M, T>)
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>;
+
+impl<M, T> Field for Wrapped<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn label(&self) -> &'static str {
+        self.0.label()
+    }
+
+    fn required(&self) -> bool {
+        self.0.required()
+    }
+
+    fn validate(&self) -> Option<Cow<'static, str>> {
+        self.0.validate()
+    }
+
+    fn set_default(&self) {
+        self.0.set_default()
+    }
+
+    fn error(&self) -> Option<Cow<'static, str>> {
+        self.0.error()
+    }
+
+    fn touch(&self) {
+        self.0.touch()
+    }
+
+    fn revalidate(&self) {
+        self.0.revalidate()
+    }
+
+    fn focus(&self) -> bool {
+        self.0.focus()
+    }
+
+    fn track(&self) {
+        Field::track(&self.0)
+    }
+}
+
+impl<M, T> AnyField for Wrapped<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn is_dirty(&self) -> bool {
+        AnyField::is_dirty(&self.0)
+    }
+
+    fn touched(&self) -> bool {
+        AnyField::touched(&self.0)
+    }
+
+    fn value_string(&self) -> Option<String> {
+        self.0.value_string()
+    }
+
+    fn set_value_string(&self, value: &str) -> Result<(), Cow<'static, str>> {
+        self.0.set_value_string(value)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<M, T> FieldStorage<T> for Wrapped<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn create(value: T, form: FormState) -> Self {
+        Self(RwSignalField::with_form(value, form))
+    }
+
+    fn snapshot(&self) -> T {
+        self.0.get_untracked()
+    }
+}
+
+impl<M, T> SignalSet for Wrapped<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn set(&self, new_value: T) {
+        self.0.set(new_value)
+    }
+
+    fn try_set(&self, new_value: T) -> Option<T> {
+        self.0.try_set(new_value)
+    }
+}
+
+impl<M, T> SignalGetUntracked for Wrapped<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    type Value = T;
+
+    fn get_untracked(&self) -> T {
+        self.0.get_untracked()
+    }
+
+    fn try_get_untracked(&self) -> Option<T> {
+        self.0.try_get_untracked()
+    }
+}
+
+impl<M, T> Clone for Wrapped<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, T> Copy for Wrapped<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+}
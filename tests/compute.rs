@@ -0,0 +1,115 @@
+use leptos::*;
+use leptos_controls::*;
+use std::borrow::Cow;
+
+fn total(controls: &OrderControls) -> u32 {
+    controls.price.get() * controls.quantity.get()
+}
+
+fn discount(controls: &OrderControls) -> u32 {
+    controls.total.get() / 10
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+struct Order {
+    price: u32,
+    quantity: u32,
+    #[field(compute = total, validate = |total: &u32| *total > 0, message = "总价不能为0")]
+    total: u32,
+    #[field(compute = discount)]
+    discount: u32,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(validate = check_order_single, storage = "single")]
+struct OrderSingle {
+    price: u32,
+    quantity: u32,
+    #[field(compute = total_single)]
+    total: u32,
+}
+
+fn total_single(controls: &OrderSingleControls) -> u32 {
+    controls.price.get() * controls.quantity.get()
+}
+
+fn check_order_single(controls: &OrderSingleControls) -> Vec<Cow<'static, str>> {
+    if controls.total.get_untracked() > 100 {
+        vec![Cow::Borrowed("总价不能超过100")]
+    } else {
+        vec![]
+    }
+}
+
+#[test]
+fn computed_fields_follow_other_fields() {
+    let runtime = create_runtime();
+    let controls = OrderControls::new(Order {
+        price: 10,
+        quantity: 2,
+        total: 0,
+        discount: 0,
+    });
+    assert_eq!(controls.total.get_untracked(), 20);
+    assert_eq!(controls.discount.get_untracked(), 2);
+
+    controls.quantity.set(5);
+    assert_eq!(
+        controls.snapshot(),
+        Order {
+            price: 10,
+            quantity: 5,
+            total: 50,
+            discount: 5,
+        }
+    );
+
+    // 计算字段只读,重置时跟随其他字段
+    controls.set_default();
+    assert_eq!(controls.total.get_untracked(), 0);
+    runtime.dispose();
+}
+
+#[test]
+fn computed_field_is_validated() {
+    let runtime = create_runtime();
+    let controls = OrderControls::new(Order::default());
+    assert_eq!(controls.validate(), vec!["总价不能为0"]);
+
+    controls.price.set(1);
+    controls.quantity.set(1);
+    assert!(controls.validate().is_empty());
+    runtime.dispose();
+}
+
+#[test]
+fn computed_field_is_read_in_validator() {
+    let runtime = create_runtime();
+    let controls = OrderSingleControls::new(OrderSingle {
+        price: 20,
+        quantity: 5,
+        total: 0,
+    });
+    assert!(controls.validate().is_empty());
+
+    controls.quantity.set(6);
+    assert_eq!(controls.validate(), vec!["总价不能超过100"]);
+    assert!(controls.try_snapshot().is_err());
+    runtime.dispose();
+}
+
+#[test]
+fn computed_field_is_tracked() {
+    let runtime = create_runtime();
+    let controls = OrderControls::new(Order::default());
+    let totals = store_value(Vec::new());
+    _ = watch(
+        move || controls.total.get(),
+        move |total, _, _| totals.update_value(|totals| totals.push(*total)),
+        false,
+    );
+    controls.price.set(3);
+    controls.quantity.set(2);
+    assert_eq!(totals.get_value(), vec![0, 6]);
+    runtime.dispose();
+}
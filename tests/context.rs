@@ -0,0 +1,63 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_blur")]
+struct Search {
+    keyword: String,
+}
+
+///
+/// 在当前所有者的子所有者中执行,模拟子组件
+///
+fn child<T: 'static>(f: impl FnOnce() -> T + 'static) -> T {
+    let (value, disposer) = as_child_of_current_owner(|f: Box<dyn FnOnce() -> T>| f())(Box::new(f));
+    std::mem::forget(disposer);
+    value
+}
+
+#[test]
+fn provided_controls_are_available_to_descendants() {
+    let runtime = create_runtime();
+    let controls = child(|| {
+        let controls = SearchControls::new(Search::default());
+        controls.provide();
+        child(move || {
+            let context = SearchControls::expect_context();
+            context.keyword.set(String::from("leptos"));
+            let form = child(FormState::use_context);
+            assert_eq!(form.map(|form| form.mode()), Some(ValidationMode::OnBlur));
+        });
+        controls
+    });
+    assert_eq!(controls.keyword.get_untracked(), "leptos");
+    runtime.dispose();
+}
+
+#[test]
+fn form_state_is_shared_with_controls() {
+    let runtime = create_runtime();
+    child(|| {
+        let controls = SearchControls::new(Search::default());
+        controls.provide();
+        let form = FormState::expect_context();
+        assert!(!form.submitted());
+
+        controls.validate();
+        assert!(form.submitted());
+        controls.set_default();
+        assert!(!form.submitted());
+    });
+    runtime.dispose();
+}
+
+#[test]
+fn context_is_missing_without_provider() {
+    let runtime = create_runtime();
+    child(|| {
+        _ = SearchControls::new(Search::default());
+        assert!(SearchControls::use_context().is_none());
+        assert!(FormState::use_context().is_none());
+    });
+    runtime.dispose();
+}
@@ -0,0 +1,165 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+struct Note {
+    #[field(validate = |title: &String| !title.is_empty(), message = "标题不能为空")]
+    title: String,
+    pinned: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+#[controls(storage = "single", mode = "on_change")]
+struct Todo {
+    #[field(validate = |text: &String| !text.is_empty(), message = "内容不能为空")]
+    text: String,
+    done: bool,
+}
+
+///
+/// 不依赖具体类型的提交逻辑
+///
+fn submit<C: Controls>(controls: C, values: &[(&str, &str)]) -> Result<C::Value, ValidationErrors> {
+    for (name, value) in values {
+        controls
+            .get_field(name)
+            .expect("field")
+            .set_value_string(value)
+            .expect("value");
+    }
+    controls.try_snapshot()
+}
+
+fn create_and_submit<C: Controls>(
+    value: C::Value,
+    values: &[(&str, &str)],
+) -> Result<C::Value, ValidationErrors> {
+    let controls = C::new(value);
+    let result = submit(controls, values);
+    controls.dispose();
+    result
+}
+
+#[test]
+fn generic_code_creates_and_submits_controls() {
+    let runtime = create_runtime();
+    assert_eq!(NoteControls::FIELDS, &["title", "pinned"]);
+    assert_eq!(
+        create_and_submit::<NoteControls>(
+            Note::default(),
+            &[("title", "leptos"), ("pinned", "true")]
+        ),
+        Ok(Note {
+            title: String::from("leptos"),
+            pinned: true,
+        })
+    );
+    assert_eq!(
+        create_and_submit::<TodoControls>(Todo::default(), &[("done", "true")])
+            .unwrap_err()
+            .to_string(),
+        "内容不能为空"
+    );
+    runtime.dispose();
+}
+
+fn assert_state<C: Controls>(controls: C, field: &str) {
+    assert!(!controls.is_dirty());
+    assert!(controls.errors().is_empty());
+
+    controls
+        .get_field(field)
+        .unwrap()
+        .set_value_string("")
+        .unwrap();
+    assert!(controls.is_dirty());
+    assert_eq!(controls.validate().len(), 1);
+    assert!(controls.form().submitted());
+    assert_eq!(controls.errors().len(), 1);
+
+    controls.set_default();
+    assert!(!controls.is_dirty());
+    assert!(!controls.form().submitted());
+}
+
+#[test]
+fn generic_code_reads_controls_state() {
+    let runtime = create_runtime();
+    assert_state(NoteControls::new(Note::default()), "title");
+    assert_state(TodoControls::new(Todo::default()), "text");
+    runtime.dispose();
+}
+
+///
+/// 委托给生成的控制器,记录聚焦的次数
+///
+#[derive(Clone, Copy)]
+struct Recorded(NoteControls, StoredValue<usize>);
+
+impl Controls for Recorded {
+    type Value = Note;
+
+    const FIELDS: &'static [&'static str] = NoteControls::FIELDS;
+
+    const ID: &'static str = NoteControls::ID;
+
+    fn new(value: Note) -> Self {
+        Self(NoteControls::new(value), store_value(0))
+    }
+
+    fn set_default(&self) {
+        self.0.set_default()
+    }
+
+    fn snapshot(&self) -> Note {
+        self.0.snapshot()
+    }
+
+    fn validate(&self) -> Vec<std::borrow::Cow<'static, str>> {
+        self.0.validate()
+    }
+
+    fn get_field(&self, name: &str) -> Option<&dyn AnyField> {
+        self.0.get_field(name)
+    }
+
+    fn form(&self) -> FormState {
+        Controls::form(&self.0)
+    }
+
+    fn is_dirty(&self) -> bool {
+        Controls::is_dirty(&self.0)
+    }
+
+    fn errors(&self) -> Vec<std::borrow::Cow<'static, str>> {
+        Controls::errors(&self.0)
+    }
+
+    fn dispose(&self) {
+        self.0.dispose()
+    }
+
+    fn focus_first_error(&self) -> bool {
+        self.1.update_value(|count| *count += 1);
+        self.0.focus_first_error()
+    }
+}
+
+#[test]
+fn submit_focuses_first_error_on_failure() {
+    let runtime = create_runtime();
+    let controls = Recorded::new(Note::default());
+    assert!(controls.submit().is_err());
+    assert_eq!(controls.1.get_value(), 1);
+
+    controls.0.title.set(String::from("leptos"));
+    assert_eq!(
+        controls.submit(),
+        Ok(Note {
+            title: String::from("leptos"),
+            pinned: false,
+        })
+    );
+    assert_eq!(controls.1.get_value(), 1);
+    runtime.dispose();
+}
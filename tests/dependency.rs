@@ -0,0 +1,57 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_touched")]
+struct Address {
+    country: String,
+    #[field(depends_on = country, on_dep_change = "reset")]
+    province: String,
+    #[field(
+        depends_on(country, province),
+        on_dep_change = "revalidate",
+        validate = |city: &String| !city.is_empty(),
+    )]
+    city: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_touched", storage = "single")]
+struct AddressSingle {
+    country: String,
+    #[field(depends_on = country, on_dep_change = "reset")]
+    province: String,
+    #[field(
+        depends_on(country, province),
+        on_dep_change = "revalidate",
+        validate = |city: &String| !city.is_empty(),
+    )]
+    city: String,
+}
+
+fn assert_dependencies<C: Controls>(controls: C) {
+    let country = controls.get_field("country").unwrap();
+    let province = controls.get_field("province").unwrap();
+    let city = controls.get_field("city").unwrap();
+    assert_eq!(city.error(), None);
+
+    // 依赖变更时重新校验,未失去焦点也会展示错误
+    province.set_value_string("广东").unwrap();
+    assert!(city.error().is_some());
+    city.set_value_string("深圳").unwrap();
+    assert_eq!(city.error(), None);
+
+    // 依赖变更时重置
+    country.set_value_string("中国").unwrap();
+    assert_eq!(province.value_string().as_deref(), Some(""));
+    assert!(!province.is_dirty());
+    assert_eq!(city.value_string().as_deref(), Some("深圳"));
+}
+
+#[test]
+fn dependent_fields_reset_or_revalidate() {
+    let runtime = create_runtime();
+    assert_dependencies(AddressControls::new(Address::default()));
+    assert_dependencies(AddressSingleControls::new(AddressSingle::default()));
+    runtime.dispose();
+}
@@ -0,0 +1,157 @@
+use leptos::*;
+use leptos_controls::*;
+use std::any::Any;
+use std::borrow::Cow;
+use std::cell::Cell;
+
+///
+/// 只实现必须方法的字段
+///
+struct Plain;
+
+impl Field for Plain {
+    fn label(&self) -> &'static str {
+        "plain"
+    }
+
+    fn required(&self) -> bool {
+        true
+    }
+
+    fn validate(&self) -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("invalid"))
+    }
+
+    fn set_default(&self) {}
+}
+
+#[test]
+fn field_hooks_have_defaults() {
+    let plain = Plain;
+    assert_eq!(plain.error(), None);
+    assert!(!plain.focus());
+    plain.touch();
+    plain.revalidate();
+    plain.track();
+    assert_eq!(plain.error(), None);
+    assert_eq!(plain.placeholder(), None);
+    assert!(plain.attrs().is_empty());
+}
+
+///
+/// 没有实现追踪的自定义字段存储
+///
+struct Untracked<M, T>(RwSignalField<M, T>)
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>;
+
+impl<M, T> Clone for Untracked<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, T> Copy for Untracked<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+}
+
+impl<M, T> Field for Untracked<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T>,
+{
+    fn label(&self) -> &'static str {
+        self.0.label()
+    }
+
+    fn required(&self) -> bool {
+        self.0.required()
+    }
+
+    fn validate(&self) -> Option<Cow<'static, str>> {
+        self.0.validate()
+    }
+
+    fn set_default(&self) {
+        self.0.set_default()
+    }
+}
+
+impl<M, T> AnyField for Untracked<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn is_dirty(&self) -> bool {
+        AnyField::is_dirty(&self.0)
+    }
+
+    fn touched(&self) -> bool {
+        AnyField::touched(&self.0)
+    }
+
+    fn value_string(&self) -> Option<String> {
+        self.0.value_string()
+    }
+
+    fn set_value_string(&self, value: &str) -> Result<(), Cow<'static, str>> {
+        self.0.set_value_string(value)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<M, T> FieldStorage<T> for Untracked<M, T>
+where
+    T: Clone + Default + 'static,
+    M: FieldMeta<Type = T> + 'static,
+{
+    fn create(value: T, form: FormState) -> Self {
+        Self(RwSignalField::with_form(value, form))
+    }
+
+    fn snapshot(&self) -> T {
+        self.0.get_untracked()
+    }
+}
+
+thread_local! {
+    static CHANGES: Cell<usize> = const { Cell::new(0) };
+}
+
+#[allow(clippy::ptr_arg)]
+fn count(_: &String, _: Option<&String>) {
+    CHANGES.set(CHANGES.get() + 1);
+}
+
+#[derive(Clone, Default, Controls)]
+struct Note {
+    #[field(with = Untracked, on_change = count)]
+    title: String,
+    #[field(with = Untracked, validate = |body: &String| !body.is_empty())]
+    body: String,
+}
+
+#[test]
+fn untracked_storage_skips_watchers() {
+    let runtime = create_runtime();
+    let controls = NoteControls::new(Note::default());
+    controls.title.set_value_string("leptos").unwrap();
+    assert_eq!(controls.snapshot().title, "leptos");
+    // 没有实现`track`时无法监听字段的变更
+    assert_eq!(CHANGES.get(), 0);
+    // 校验仍然生效,但没有实现`error`时不展示校验错误
+    assert_eq!(controls.validate().len(), 1);
+    assert_eq!(controls.body.error(), None);
+    runtime.dispose();
+}
@@ -0,0 +1,64 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+struct Shipping {
+    #[field(label = "收件人")]
+    receiver: String,
+    #[field(skip)]
+    id: u64,
+    phone_number: String,
+    #[field(readonly)]
+    r#type: u8,
+}
+
+#[test]
+fn field_enum_lists_control_fields() {
+    assert_eq!(
+        ShippingField::ALL,
+        [
+            ShippingField::Receiver,
+            ShippingField::PhoneNumber,
+            ShippingField::Type
+        ]
+    );
+    assert_eq!(ShippingField::PhoneNumber.name(), "phone_number");
+    assert_eq!(ShippingField::Receiver.label(), "收件人");
+    assert_eq!(ShippingField::Type.to_string(), "type");
+}
+
+#[test]
+fn field_enum_parses_names() {
+    assert_eq!(
+        "phone_number".parse::<ShippingField>(),
+        Ok(ShippingField::PhoneNumber)
+    );
+    let error = "id".parse::<ShippingField>().unwrap_err();
+    assert_eq!(error.name(), "id");
+    assert_eq!(error.to_string(), "unknown field `id`");
+}
+
+#[test]
+fn fields_are_accessed_by_enum_and_name() {
+    let runtime = create_runtime();
+    let controls = ShippingControls::new(Shipping::default());
+    controls
+        .field(ShippingField::PhoneNumber)
+        .set_value_string("10086")
+        .unwrap();
+    assert_eq!(controls.phone_number.get_untracked(), "10086");
+    assert_eq!(
+        controls
+            .get_field("phone_number")
+            .and_then(|field| field.value_string()),
+        Some(String::from("10086"))
+    );
+    assert!(controls.get_field("id").is_none());
+
+    let names = controls
+        .fields()
+        .map(|(field, _)| field.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ShippingControls::FIELDS);
+    runtime.dispose();
+}
@@ -0,0 +1,49 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+struct Login {
+    #[field(validate = |name: &String| !name.is_empty(), focus_order = 1)]
+    name: String,
+    #[field(validate = |password: &String| password.len() >= 6, focus_order = 0)]
+    password: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(storage = "single")]
+struct LoginSingle {
+    #[field(validate = |name: &String| !name.is_empty())]
+    name: String,
+}
+
+#[test]
+fn focus_first_error_without_node_refs() {
+    let runtime = create_runtime();
+    let controls = LoginControls::new(Login::default());
+    assert!(!controls.focus_first_error());
+    assert!(!controls.name.focus());
+
+    let controls = LoginSingleControls::new(LoginSingle::default());
+    assert!(!controls.focus_first_error());
+    runtime.dispose();
+}
+
+#[test]
+fn focus_first_error_with_unmounted_node_refs() {
+    let runtime = create_runtime();
+    let controls = LoginControls::new(Login::default());
+    let name = controls.name.node_ref::<html::Input>();
+    let password = controls.password.node_ref::<html::Input>();
+    assert!(name.get_untracked().is_none());
+    assert!(password.get_untracked().is_none());
+
+    // 元素没有挂载时无法聚焦
+    assert!(!controls.focus_first_error());
+    assert!(!controls.password.focus());
+
+    // 没有校验错误时不会聚焦
+    controls.name.set(String::from("leptos"));
+    controls.password.set(String::from("controls"));
+    assert!(!controls.focus_first_error());
+    runtime.dispose();
+}
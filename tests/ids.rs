@@ -0,0 +1,40 @@
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+struct UserProfile {
+    name: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[allow(clippy::upper_case_acronyms)]
+struct HTTPConfig {
+    base_url: String,
+}
+
+#[derive(Clone, Default, Controls)]
+struct Login2Fa {
+    code: String,
+}
+
+#[test]
+fn controls_id_is_kebab_case_type_name() {
+    assert_eq!(UserProfileControls::ID, "user-profile");
+    assert_eq!(HTTPConfigControls::ID, "http-config");
+    assert_eq!(Login2FaControls::ID, "login2-fa");
+}
+
+#[test]
+fn field_ids_use_prefix_and_field_name() {
+    let ids = FieldIds::new(UserProfileControls::ID, "name");
+    assert_eq!(ids.input(), "user-profile-name");
+    assert_eq!(ids.label(), "user-profile-name-label");
+    assert_eq!(ids.error(), "user-profile-name-error");
+    assert_eq!(ids.help(), "user-profile-name-help");
+
+    // 相同的前缀和字段名称总是得到相同的id,与创建顺序无关
+    let _ = FieldIds::new("other", "name");
+    assert_eq!(
+        FieldIds::new(UserProfileControls::ID, "name").input(),
+        ids.input()
+    );
+}
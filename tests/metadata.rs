@@ -0,0 +1,44 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+struct Account {
+    #[field(
+        placeholder = "请输入账号",
+        help = "登录使用的账号",
+        attr(autocomplete = "username", data_test = "account")
+    )]
+    name: String,
+    #[field(order = 1, attr(r#type = "tel"), attr(inputmode = "numeric"))]
+    mobile: String,
+    #[field(hidden, readonly, order = 2)]
+    id: u64,
+}
+
+#[test]
+fn fields_expose_render_metadata() {
+    let runtime = create_runtime();
+    let controls = AccountControls::new(Account::default());
+    let name = controls.field(AccountField::Name);
+    assert_eq!(name.placeholder(), Some("请输入账号"));
+    assert_eq!(name.help(), Some("登录使用的账号"));
+    assert!(!name.hidden());
+    assert_eq!(name.order(), 0);
+    // 下划线转换为连字符
+    assert_eq!(
+        name.attrs(),
+        &[("autocomplete", "username"), ("data-test", "account")]
+    );
+
+    // 多次声明的属性按照顺序合并,原始标识符去掉前缀
+    let mobile = controls.field(AccountField::Mobile);
+    assert_eq!(mobile.order(), 1);
+    assert_eq!(mobile.attrs(), &[("type", "tel"), ("inputmode", "numeric")]);
+    assert_eq!(mobile.placeholder(), None);
+
+    let id = controls.field(AccountField::Id);
+    assert!(id.hidden());
+    assert_eq!(id.order(), 2);
+    assert!(id.attrs().is_empty());
+    runtime.dispose();
+}
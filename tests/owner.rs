@@ -0,0 +1,125 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Debug, Default, Clone, PartialEq, Controls)]
+struct Profile {
+    #[field(label = "名称", validate = |name: &String| !name.is_empty())]
+    name: String,
+    #[field(readonly)]
+    age: u32,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Controls)]
+#[controls(storage = "single")]
+struct Settings {
+    theme: String,
+    #[field(depends_on = theme, on_dep_change = "reset")]
+    accent: String,
+}
+
+///
+/// 创建一个组件的所有者,返回所有者和销毁函数
+///
+fn component() -> (Owner, Disposer) {
+    as_child_of_current_owner(|_: ()| Owner::current().expect("owner"))(())
+}
+
+#[test]
+fn created_in_event_handler_is_disposed_manually() {
+    let runtime = create_runtime();
+
+    let controls = ProfileControls::new(Profile::default());
+    controls.name.set(String::from("leptos"));
+    assert_eq!(controls.name.try_get(), Some(String::from("leptos")));
+    assert!(!controls.is_disposed());
+
+    controls.dispose();
+    assert!(controls.is_disposed());
+    assert_eq!(controls.name.try_get(), None);
+    assert_eq!(controls.age.try_get_untracked(), None);
+    runtime.dispose();
+}
+
+#[test]
+fn created_in_event_handler_with_component_owner() {
+    let runtime = create_runtime();
+    let (owner, disposer) = component();
+
+    let controls = with_owner(owner, || ProfileControls::new(Profile::default()));
+    controls.name.set(String::from("leptos"));
+    assert_eq!(
+        controls.snapshot(),
+        Profile {
+            name: String::from("leptos"),
+            age: 0
+        }
+    );
+
+    drop(disposer);
+    assert!(controls.is_disposed());
+    assert_eq!(controls.name.try_get_untracked(), None);
+    runtime.dispose();
+}
+
+#[test]
+fn dispose_keeps_parent_owner_alive() {
+    let runtime = create_runtime();
+    let (owner, _disposer) = component();
+
+    let (signal, controls) = with_owner(owner, || {
+        (
+            create_rw_signal(1),
+            ProfileControls::new(Profile::default()),
+        )
+    });
+    controls.dispose();
+    controls.dispose();
+
+    assert!(controls.is_disposed());
+    assert_eq!(controls.name.try_get_untracked(), None);
+    assert_eq!(signal.try_get_untracked(), Some(1));
+    runtime.dispose();
+}
+
+#[test]
+fn created_in_effect_is_disposed_when_effect_reruns() {
+    let runtime = create_runtime();
+    let trigger = create_trigger();
+    let created = store_value(Vec::new());
+    create_isomorphic_effect(move |_| {
+        trigger.track();
+        created.update_value(|created| created.push(ProfileControls::new(Profile::default())));
+    });
+    trigger.notify();
+
+    let created = created.get_value();
+    assert_eq!(created.len(), 2);
+    assert!(created[0].is_disposed());
+    assert!(!created[1].is_disposed());
+    runtime.dispose();
+}
+
+#[test]
+fn dispose_bound_controls() {
+    let runtime = create_runtime();
+    let source = create_rw_signal(Settings {
+        theme: String::from("dark"),
+        accent: String::from("blue"),
+    });
+
+    let controls = SettingsControls::bind(source);
+    controls.theme.set(String::from("light"));
+    assert_eq!(
+        source.get_untracked(),
+        Settings {
+            theme: String::from("light"),
+            accent: String::new(),
+        }
+    );
+
+    controls.dispose();
+    source.update(|settings| settings.theme = String::from("dark"));
+    assert_eq!(controls.theme.try_get_untracked(), None);
+    assert_eq!(source.get_untracked().theme, "dark");
+    runtime.dispose();
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::child;
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+struct User {
+    #[field(readonly, validate = |id: &u64| *id > 0)]
+    id: u64,
+    name: String,
+}
+
+#[test]
+fn standalone_readonly_field_has_no_form_state() {
+    let runtime = create_runtime();
+    let (field, disposer) = child(|| SignalField::<UserId, u64>::new(7));
+    assert!(field.is_static());
+    assert_eq!(field.get_untracked(), 7);
+    // 没有表单状态,校验失败也不会展示错误
+    let (invalid, _disposer) = child(|| SignalField::<UserId, u64>::new(0));
+    assert!(invalid.validate().is_some());
+    assert_eq!(invalid.error(), None);
+
+    // 常量随创建时的所有者一起销毁
+    drop(disposer);
+    assert_eq!(field.try_get_untracked(), None);
+    runtime.dispose();
+}
+
+#[test]
+fn readonly_field_reveals_errors_after_submit() {
+    let runtime = create_runtime();
+    let controls = UserControls::new(User::default());
+    assert!(controls.id.is_static());
+    assert_eq!(controls.id.error(), None);
+
+    assert_eq!(controls.validate().len(), 1);
+    assert!(controls.id.error().is_some());
+
+    // 只读字段不会被重置
+    controls.name.set(String::from("leptos"));
+    controls.set_default();
+    assert_eq!(controls.snapshot(), User::default());
+    runtime.dispose();
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+#[controls(storage = "single")]
+struct UserSingle {
+    #[field(readonly)]
+    id: u64,
+    name: String,
+}
+
+#[test]
+fn readonly_field_follows_bound_source() {
+    let runtime = create_runtime();
+    let id = create_rw_signal(1);
+    let controls = UserControls::with_sources(
+        User {
+            id: 0,
+            name: String::from("leptos"),
+        },
+        UserSources::default().id(id),
+    );
+    assert!(!controls.id.is_static());
+    assert_eq!(controls.id.get_untracked(), 1);
+
+    id.set(2);
+    assert_eq!(
+        controls.snapshot(),
+        User {
+            id: 2,
+            name: String::from("leptos"),
+        }
+    );
+    runtime.dispose();
+}
+
+#[test]
+fn readonly_field_without_source_keeps_initial_value() {
+    let runtime = create_runtime();
+    let controls = UserSingleControls::with_sources(
+        UserSingle {
+            id: 3,
+            name: String::new(),
+        },
+        UserSingleSources::default(),
+    );
+    assert!(controls.id.is_static());
+    assert_eq!(controls.snapshot().id, 3);
+
+    let id = create_rw_signal(4);
+    let controls = UserSingleControls::with_sources(
+        UserSingle::default(),
+        UserSingleSources::default().id(id),
+    );
+    id.set(5);
+    assert_eq!(controls.snapshot().id, 5);
+    runtime.dispose();
+}
+
+#[test]
+fn standalone_readonly_field_from_signal() {
+    let runtime = create_runtime();
+    let id = create_rw_signal(1);
+    let field = SignalField::<UserId, u64>::from_signal(id.into());
+    let ids = store_value(Vec::new());
+    create_effect(move |_| {
+        let id = field.get();
+        ids.update_value(|ids| ids.push(id));
+    });
+    id.set(2);
+    assert_eq!(ids.get_value(), vec![1, 2]);
+    runtime.dispose();
+}
@@ -0,0 +1,79 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Controls)]
+#[controls(storage = "single")]
+struct Profile {
+    name: String,
+    age: u32,
+    #[field(readonly)]
+    id: u64,
+}
+
+///
+/// 统计副作用的执行次数
+///
+fn count_runs(f: impl Fn() + 'static) -> StoredValue<usize> {
+    let runs = store_value(0);
+    create_effect(move |_| {
+        f();
+        runs.update_value(|runs| *runs += 1);
+    });
+    runs
+}
+
+#[test]
+fn writing_a_slice_only_notifies_its_subscribers() {
+    let runtime = create_runtime();
+    let controls = ProfileControls::new(Profile::default());
+    let name = count_runs(move || controls.name.with(|_| ()));
+    let age = count_runs(move || controls.age.with(|_| ()));
+    let root = count_runs(move || controls.name.root().track());
+    assert_eq!(
+        (name.get_value(), age.get_value(), root.get_value()),
+        (1, 1, 1)
+    );
+
+    controls.name.set(String::from("leptos"));
+    assert_eq!(
+        (name.get_value(), age.get_value(), root.get_value()),
+        (2, 1, 2)
+    );
+
+    controls.age.update(|age| *age += 1);
+    assert_eq!(
+        (name.get_value(), age.get_value(), root.get_value()),
+        (2, 2, 3)
+    );
+    runtime.dispose();
+}
+
+#[test]
+fn slices_share_one_struct() {
+    let runtime = create_runtime();
+    let controls = ProfileControls::new(Profile {
+        name: String::from("leptos"),
+        age: 18,
+        id: 7,
+    });
+    controls.age.set(20);
+    assert_eq!(
+        controls.name.root().get_untracked(),
+        Profile {
+            name: String::from("leptos"),
+            age: 20,
+            id: 7,
+        }
+    );
+    assert_eq!(controls.snapshot(), controls.name.root().get_untracked());
+
+    controls.set_default();
+    assert_eq!(
+        controls.snapshot(),
+        Profile {
+            id: 7,
+            ..Profile::default()
+        }
+    );
+    runtime.dispose();
+}
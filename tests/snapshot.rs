@@ -0,0 +1,163 @@
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Debug, Default, Clone, PartialEq, Controls)]
+struct Account {
+    #[field(validate = |name: &String| !name.is_empty(), message = "名称不能为空")]
+    name: String,
+    #[field(validate = |age: &u32| *age >= 18, message = "年龄不能小于18")]
+    age: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Adult {
+    name: String,
+}
+
+impl TryFrom<Account> for Adult {
+    type Error = &'static str;
+
+    fn try_from(account: Account) -> Result<Self, Self::Error> {
+        if account.name == "root" {
+            Err("名称不可用")
+        } else {
+            Ok(Adult { name: account.name })
+        }
+    }
+}
+
+#[test]
+fn try_snapshot_returns_errors() {
+    let runtime = create_runtime();
+    let controls = AccountControls::new(Account::default());
+
+    let errors = controls.try_snapshot().unwrap_err();
+    assert_eq!(errors.to_string(), "名称不能为空|年龄不能小于18");
+    assert_eq!(errors.len(), 2);
+    runtime.dispose();
+}
+
+#[test]
+fn try_snapshot_returns_value() {
+    let runtime = create_runtime();
+    let controls = AccountControls::new(Account::default());
+    controls.name.set(String::from("leptos"));
+    controls.age.set(18);
+
+    assert_eq!(
+        controls.try_snapshot(),
+        Ok(Account {
+            name: String::from("leptos"),
+            age: 18
+        })
+    );
+    assert_eq!(Controls::try_snapshot(&controls), Ok(controls.snapshot()));
+    runtime.dispose();
+}
+
+#[test]
+fn try_snapshot_into_converts_value() {
+    let runtime = create_runtime();
+    let controls = AccountControls::new(Account {
+        name: String::from("leptos"),
+        age: 20,
+    });
+    assert_eq!(
+        controls.try_snapshot_into::<Adult>(),
+        Ok(Adult {
+            name: String::from("leptos")
+        })
+    );
+
+    controls.name.set(String::from("root"));
+    assert_eq!(
+        controls.try_snapshot_into::<Adult>(),
+        Err(ValidationErrors::from("名称不可用"))
+    );
+
+    controls.age.set(10);
+    assert_eq!(
+        controls
+            .try_snapshot_into::<Adult>()
+            .unwrap_err()
+            .to_string(),
+        "年龄不能小于18"
+    );
+    runtime.dispose();
+}
+
+fn default_source() -> &'static str {
+    "web"
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Controls)]
+struct Article {
+    #[field(skip)]
+    id: u64,
+    #[field(skip, default)]
+    version: u32,
+    #[field(skip, default = "default_source")]
+    source: &'static str,
+    #[field(validate = |title: &String| !title.is_empty())]
+    title: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Controls)]
+#[controls(storage = "single")]
+struct ArticleSingle {
+    #[field(skip)]
+    id: u64,
+    #[field(skip, default)]
+    version: u32,
+    title: String,
+}
+
+#[test]
+fn skipped_fields_are_carried_through_snapshot() {
+    let runtime = create_runtime();
+    let controls = ArticleControls::new(Article {
+        id: 7,
+        version: 3,
+        source: "app",
+        title: String::from("leptos"),
+    });
+    assert_eq!(ArticleControls::FIELDS, &["title"]);
+    assert_eq!(controls.fields().count(), 1);
+
+    controls.title.set(String::from("controls"));
+    assert_eq!(
+        controls.snapshot(),
+        Article {
+            id: 7,
+            version: 0,
+            source: "web",
+            title: String::from("controls"),
+        }
+    );
+
+    // 跳过的字段不参与重置和校验
+    controls.set_default();
+    assert_eq!(controls.snapshot().id, 7);
+    assert_eq!(controls.validate().len(), 1);
+    runtime.dispose();
+}
+
+#[test]
+fn skipped_fields_are_carried_through_single_snapshot() {
+    let runtime = create_runtime();
+    let controls = ArticleSingleControls::new(ArticleSingle {
+        id: 7,
+        version: 3,
+        title: String::from("leptos"),
+    });
+    controls.set_default();
+    assert_eq!(
+        controls.snapshot(),
+        ArticleSingle {
+            id: 7,
+            version: 0,
+            title: String::new(),
+        }
+    );
+    runtime.dispose();
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::Wrapped;
+use leptos::*;
+use leptos_controls::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+thread_local! {
+    static CHANGES: RefCell<Vec<(String, Option<String>)>> = const { RefCell::new(Vec::new()) };
+}
+
+#[allow(clippy::ptr_arg)]
+fn record(new: &String, old: Option<&String>) {
+    CHANGES.with_borrow_mut(|changes| changes.push((new.clone(), old.cloned())));
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Controls)]
+struct Address {
+    #[field(with = Wrapped, on_change = record)]
+    country: String,
+    #[field(with = Wrapped, depends_on = country, on_dep_change = "reset")]
+    city: String,
+}
+
+#[test]
+fn custom_storage_creates_and_snapshots_fields() {
+    let runtime = create_runtime();
+    let controls = AddressControls::new(Address {
+        country: String::from("中国"),
+        city: String::from("上海"),
+    });
+    assert!(controls
+        .field(AddressField::Country)
+        .as_any()
+        .is::<Wrapped<AddressCountry, String>>());
+
+    controls.city.set(String::from("北京"));
+    assert_eq!(controls.snapshot().city, "北京");
+    controls.set_default();
+    assert_eq!(controls.snapshot(), Address::default());
+    runtime.dispose();
+}
+
+#[test]
+fn custom_storage_keeps_watchers() {
+    let runtime = create_runtime();
+    let controls = AddressControls::new(Address {
+        country: String::from("中国"),
+        city: String::from("上海"),
+    });
+    let names = Rc::new(RefCell::new(Vec::new()));
+    let stop = controls.watch_any({
+        let names = names.clone();
+        move |name, _| names.borrow_mut().push(name)
+    });
+
+    controls.country.set(String::from("日本"));
+    assert_eq!(
+        CHANGES.with_borrow(Clone::clone),
+        vec![(String::from("日本"), Some(String::from("中国")))]
+    );
+    assert_eq!(controls.snapshot().city, "");
+    // 依赖字段在当前字段的监听之前重置
+    names.borrow_mut().sort();
+    assert_eq!(*names.borrow(), vec!["city", "country"]);
+
+    stop();
+    controls.city.set(String::from("东京"));
+    assert_eq!(names.borrow().len(), 2);
+    runtime.dispose();
+}
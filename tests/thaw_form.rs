@@ -0,0 +1,110 @@
+#![cfg(feature = "thaw")]
+
+use leptos::*;
+use leptos_controls::*;
+
+#[derive(Clone, Default, Controls)]
+#[controls(thaw_form)]
+struct UserProfile {
+    #[field(
+        label = "名称",
+        help = "真实姓名",
+        attr(autocomplete = "name", data_test = "name")
+    )]
+    name: String,
+    enabled: bool,
+}
+
+// thaw的输入组件只能在浏览器中渲染,这里只检查生成的表单组件接收`id`
+#[allow(dead_code)]
+fn editor_form(controls: UserProfileControls) -> impl IntoView {
+    view! { <UserProfileForm controls id="editor" /> }
+}
+
+fn render(view: impl IntoView) -> String {
+    view.into_view().render_to_string().to_string()
+}
+
+#[test]
+fn field_label_links_label_to_input() {
+    let runtime = create_runtime();
+    let controls = UserProfileControls::new(UserProfile::default());
+    let ids = FieldIds::new(UserProfileControls::ID, "name");
+    let html = render(view! {
+        <FieldLabel field=controls.name ids=ids.clone()>
+            <input id=ids.input() />
+        </FieldLabel>
+    });
+    assert!(html.contains(r#"for="user-profile-name""#), "{html}");
+    assert!(html.contains(r#"id="user-profile-name""#), "{html}");
+    assert!(
+        html.contains(r#"aria-labelledby="user-profile-name-label""#),
+        "{html}"
+    );
+    assert!(
+        html.contains(r#"aria-describedby="user-profile-name-help""#),
+        "{html}"
+    );
+    assert!(html.contains(r#"id="user-profile-name-help""#), "{html}");
+    assert!(html.contains(r#"id="user-profile-name-error""#), "{html}");
+    runtime.dispose();
+}
+
+#[test]
+fn field_label_without_labelable_input() {
+    let runtime = create_runtime();
+    let controls = UserProfileControls::new(UserProfile::default());
+    let ids = FieldIds::new(UserProfileControls::ID, "enabled");
+    let html = render(view! {
+        <FieldLabel field=controls.enabled ids labelable=false>
+            <input type="checkbox" />
+        </FieldLabel>
+    });
+    assert!(!html.contains("for="), "{html}");
+    assert!(
+        html.contains(r#"aria-labelledby="user-profile-enabled-label""#),
+        "{html}"
+    );
+    runtime.dispose();
+}
+
+#[test]
+fn field_label_without_ids_keeps_layout() {
+    let runtime = create_runtime();
+    let controls = UserProfileControls::new(UserProfile::default());
+    let html = render(view! {
+        <FieldLabel field=controls.name>
+            <input />
+        </FieldLabel>
+    });
+    assert!(html.contains("名称"), "{html}");
+    assert!(!html.contains("for="), "{html}");
+    assert!(!html.contains("aria-labelledby"), "{html}");
+    runtime.dispose();
+}
+
+#[derive(Clone, Default, Controls)]
+struct Login {
+    #[field(validate = |name: &String| !name.is_empty(), message = "请输入名称")]
+    name: String,
+}
+
+#[test]
+fn field_error_shows_after_submit() {
+    let runtime = create_runtime();
+    let controls = LoginControls::new(Login::default());
+    let invalid = field_invalid(controls.name);
+    assert!(!invalid.get_untracked());
+    assert!(!render(view! { <FieldError field=controls.name /> }).contains("请输入名称"));
+
+    assert!(controls.try_snapshot().is_err());
+    assert!(invalid.get_untracked());
+    let html = render(view! { <FieldError field=controls.name id="login-name-error" /> });
+    assert!(html.contains("请输入名称"), "{html}");
+    assert!(html.contains(r#"id="login-name-error""#), "{html}");
+    assert!(html.contains(r#"role="alert""#), "{html}");
+
+    controls.name.set(String::from("leptos"));
+    assert!(!invalid.get_untracked());
+    runtime.dispose();
+}
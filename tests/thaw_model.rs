@@ -0,0 +1,99 @@
+#![cfg(feature = "thaw")]
+
+mod common;
+
+use common::child;
+use leptos::*;
+use leptos_controls::*;
+use thaw_utils::Model;
+
+#[derive(Clone, Default, Controls)]
+struct Profile {
+    age: u32,
+    nickname: Option<String>,
+    #[field(readonly)]
+    id: u64,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(storage = "single")]
+struct ProfileSingle {
+    age: u32,
+    nickname: Option<String>,
+}
+
+#[test]
+fn models_are_reused_across_renders() {
+    let runtime = create_runtime();
+    let controls = ProfileControls::new(Profile::default());
+    // 每次渲染在新的子组件中转换,销毁之前渲染的子组件不影响之后的转换
+    for age in 1..=3 {
+        let ((model, string), disposer) = child(move || {
+            let model: Model<Option<u32>> = controls.age.into();
+            (model, string_model(controls.age))
+        });
+        drop(disposer);
+        model.set(Some(age));
+        assert_eq!(controls.age.get_untracked(), age);
+        string.set((age * 10).to_string());
+        assert_eq!(controls.age.get_untracked(), age * 10);
+    }
+
+    let controls = ProfileSingleControls::new(ProfileSingle::default());
+    for age in 1..=3 {
+        let (model, disposer) = child(move || Model::<u32>::from(controls.age));
+        drop(disposer);
+        model.set(age);
+        assert_eq!(controls.age.get_untracked(), age);
+    }
+    runtime.dispose();
+}
+
+#[test]
+fn models_outlive_the_rendering_owner() {
+    let runtime = create_runtime();
+    let controls = ProfileSingleControls::new(ProfileSingle::default());
+    // 在子组件中转换,子组件销毁后字段缓存的Model仍然可以写入
+    let (model, disposer) = child(move || Model::<Option<u32>>::from(controls.age));
+    drop(disposer);
+    model.set(Some(18));
+    assert_eq!(controls.age.get_untracked(), 18);
+    model.set(None);
+    assert_eq!(controls.age.get_untracked(), 0);
+
+    let nickname: Model<String> = controls.nickname.into();
+    nickname.set(String::from("leptos"));
+    assert_eq!(controls.nickname.get_untracked().as_deref(), Some("leptos"));
+    nickname.set(String::new());
+    assert_eq!(controls.nickname.get_untracked(), None);
+    runtime.dispose();
+}
+
+#[test]
+fn readonly_string_model_ignores_writes() {
+    let runtime = create_runtime();
+    let controls = ProfileControls::new(Profile {
+        id: 7,
+        ..Default::default()
+    });
+    let model = string_model(controls.id);
+    assert_eq!(model.get_untracked(), "7");
+    model.set(String::from("8"));
+    assert_eq!(controls.id.get_untracked(), 7);
+    runtime.dispose();
+}
+
+#[test]
+fn field_state_cache_creates_value_once() {
+    let runtime = create_runtime();
+    let controls = ProfileControls::new(Profile::default());
+    let state = controls.age.state();
+    let first = state.cached("key", || create_rw_signal(1));
+    let second = state.cached("key", || create_rw_signal(2));
+    assert_eq!(first, second);
+    assert_eq!(second.get_untracked(), 1);
+    // 键不同时分别缓存
+    let other = state.cached("other", || create_rw_signal(3));
+    assert_ne!(first, other);
+    runtime.dispose();
+}
@@ -0,0 +1,13 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[cfg(feature = "thaw")]
+#[test]
+fn thaw_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/thaw/*.rs");
+    t.pass("tests/ui/thaw/pass/*.rs");
+}
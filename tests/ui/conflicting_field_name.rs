@@ -0,0 +1,19 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct Order {
+    field: String,
+    controls: String,
+    #[field(readonly)]
+    sources: String,
+    user_name: String,
+    user__name: String,
+}
+
+#[derive(Clone, Controls)]
+#[controls(thaw_form)]
+struct Login {
+    form: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct User {
+    #[field(default)]
+    id: u64,
+    name: String,
+}
+
+fn main() {}
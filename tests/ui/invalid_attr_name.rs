@@ -0,0 +1,11 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct User {
+    #[field(attr(data__test = "name"))]
+    name: String,
+    #[field(attr(_autocomplete = "off"))]
+    password: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct User {
+    #[field(validate = "is not blank")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use leptos_controls::Controls;
+
+fn is_positive(value: &i32) -> bool {
+    *value > 0
+}
+
+#[derive(Clone, Controls)]
+struct User {
+    #[field(validate = is_positive)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct User {
+    #[field(readonly, with = leptos_controls::RwSignalField)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Default, Controls)]
+#[controls(thaw_form)]
+struct Settings {
+    #[field(attr(data_test = "enabled"))]
+    enabled: bool,
+    #[field(readonly, attr(data_test = "verified"))]
+    verified: bool,
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use leptos_controls::{Controls, SelectOptions};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+enum Level {
+    #[default]
+    Low,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("low")
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(Level::Low)
+    }
+}
+
+impl SelectOptions for Level {
+    fn options() -> Vec<Self> {
+        vec![Level::Low]
+    }
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(thaw_form)]
+struct Task {
+    #[field(attr(data_test = "level"))]
+    level: Level,
+    #[field(widget = "select", attr(data_test = "priority"))]
+    priority: Level,
+    #[field(widget = "text", attr(data_test = "tag"))]
+    tag: Level,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Default, Controls)]
+#[controls(thaw_form)]
+struct Order {
+    #[field(widget = "number")]
+    amount: String,
+    #[field(widget = "number")]
+    paid: bool,
+}
+
+fn main() {}
@@ -0,0 +1,99 @@
+use leptos::*;
+use leptos_controls::*;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+enum Level {
+    #[default]
+    Low,
+    High,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Low => f.write_str("low"),
+            Level::High => f.write_str("high"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "low" => Ok(Level::Low),
+            "high" => Ok(Level::High),
+            _ => Err(format!("unknown level `{}`", value)),
+        }
+    }
+}
+
+impl SelectOptions for Level {
+    fn options() -> Vec<Self> {
+        vec![Level::Low, Level::High]
+    }
+}
+
+// 实现了`Display`和`FromStr`但不是下拉选项的类型渲染为文本输入框
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Code(String);
+
+impl Display for Code {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Code {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Code(value.to_owned()))
+    }
+}
+
+// 数字类型的别名同样可以使用数字输入框
+type Amount = f64;
+
+#[derive(Clone, Default, Controls)]
+#[controls(thaw_form)]
+struct Task {
+    #[field(attr(autocomplete = "off", data_test = "title"), placeholder = "标题")]
+    title: String,
+    #[field(widget = "password")]
+    secret: String,
+    #[field(widget = "textarea")]
+    content: String,
+    estimate: u32,
+    #[field(widget = "number")]
+    amount: Amount,
+    done: bool,
+    level: Level,
+    #[field(widget = "select")]
+    priority: Level,
+    code: Code,
+    nickname: Option<String>,
+    #[field(readonly, attr(data_test = "owner"))]
+    owner: Level,
+    #[field(hidden)]
+    token: String,
+}
+
+#[allow(dead_code)]
+fn task_form(controls: TaskControls) -> impl IntoView {
+    view! {
+        <TaskForm controls id="editor" on_submit=|_: Task| {}>
+            <FieldSlot slot name="token">
+                <input type="hidden" />
+            </FieldSlot>
+            <FooterSlot slot>
+                <button type="submit">"保存"</button>
+            </FooterSlot>
+        </TaskForm>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct User {
+    #[field(readonly = "yes", label = 1)]
+    name: String,
+}
+
+fn main() {}
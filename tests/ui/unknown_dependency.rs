@@ -0,0 +1,14 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct Address {
+    country: String,
+    #[field(depends_on = "contry", on_dep_change = "reset")]
+    province: String,
+    #[field(depends_on(city))]
+    city: String,
+    #[field(on_dep_change = "revalidate")]
+    street: String,
+}
+
+fn main() {}
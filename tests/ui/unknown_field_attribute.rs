@@ -0,0 +1,9 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+struct User {
+    #[field(lable = "名称")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+#[controls(mode = "on_blurred")]
+struct User {
+    #[field(mode = "change")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use leptos_controls::Controls;
+
+#[derive(Clone, Controls)]
+enum Kind {
+    A,
+    B,
+}
+
+#[derive(Clone, Controls)]
+struct Tuple(String);
+
+fn main() {}
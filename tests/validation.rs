@@ -0,0 +1,233 @@
+mod common;
+
+use common::{child, Wrapped};
+use leptos::*;
+use leptos_controls::*;
+
+#[allow(clippy::ptr_arg)]
+fn not_empty(value: &String) -> bool {
+    !value.is_empty()
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_change")]
+struct OnChange {
+    #[field(validate = not_empty)]
+    name: String,
+    #[field(validate = not_empty, mode = "on_submit")]
+    code: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_change", storage = "single")]
+struct OnChangeSingle {
+    #[field(validate = not_empty)]
+    name: String,
+    #[field(validate = not_empty, mode = "on_submit")]
+    code: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_change")]
+struct OnChangeWrapped {
+    #[field(validate = not_empty, with = Wrapped)]
+    name: String,
+    #[field(validate = not_empty, mode = "on_submit", with = Wrapped)]
+    code: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_blur")]
+struct OnBlur {
+    #[field(validate = not_empty)]
+    name: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_blur", storage = "single")]
+struct OnBlurSingle {
+    #[field(validate = not_empty)]
+    name: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_touched")]
+struct OnTouched {
+    #[field(validate = not_empty, with = Wrapped)]
+    name: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(mode = "on_touched", storage = "single")]
+struct OnTouchedSingle {
+    #[field(validate = not_empty)]
+    name: String,
+}
+
+#[derive(Clone, Default, Controls)]
+struct OnSubmit {
+    #[field(validate = not_empty)]
+    name: String,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(storage = "single")]
+struct OnSubmitSingle {
+    #[field(validate = not_empty, with = Wrapped)]
+    name: String,
+}
+
+///
+/// 获取控制器的字段
+///
+fn field<'a, C: Controls>(controls: &'a C, name: &str) -> &'a dyn AnyField {
+    controls.get_field(name).expect("field")
+}
+
+fn assert_on_change<C: Controls>(controls: C) {
+    let name = field(&controls, "name");
+    let code = field(&controls, "code");
+    assert_eq!(name.error(), None);
+
+    name.set_value_string("leptos").unwrap();
+    assert_eq!(name.error(), None);
+    assert!(name.is_dirty());
+    name.set_value_string("").unwrap();
+    assert!(name.error().is_some());
+
+    // 单独配置的校验模式
+    code.set_value_string("").unwrap();
+    assert_eq!(code.error(), None);
+    assert_eq!(controls.validate().len(), 2);
+    assert!(code.error().is_some());
+
+    controls.set_default();
+    assert_eq!(name.error(), None);
+    assert_eq!(code.error(), None);
+    assert!(!name.is_dirty());
+}
+
+#[test]
+fn on_change_reveals_errors_after_change() {
+    let runtime = create_runtime();
+    assert_on_change(OnChangeControls::new(OnChange::default()));
+    assert_on_change(OnChangeSingleControls::new(OnChangeSingle::default()));
+    assert_on_change(OnChangeWrappedControls::new(OnChangeWrapped::default()));
+    runtime.dispose();
+}
+
+fn assert_on_blur<C: Controls>(controls: C) {
+    let name = field(&controls, "name");
+    name.set_value_string("leptos").unwrap();
+    name.set_value_string("").unwrap();
+    assert_eq!(name.error(), None);
+
+    name.touch();
+    assert!(name.touched());
+    assert!(name.error().is_some());
+
+    // 失去焦点时才会重新校验
+    name.set_value_string("leptos").unwrap();
+    assert!(name.error().is_some());
+    name.touch();
+    assert_eq!(name.error(), None);
+}
+
+#[test]
+fn on_blur_reveals_errors_when_touched() {
+    let runtime = create_runtime();
+    assert_on_blur(OnBlurControls::new(OnBlur::default()));
+    assert_on_blur(OnBlurSingleControls::new(OnBlurSingle::default()));
+    runtime.dispose();
+}
+
+fn assert_on_touched<C: Controls>(controls: C) {
+    let name = field(&controls, "name");
+    name.set_value_string("").unwrap();
+    assert_eq!(name.error(), None);
+
+    name.touch();
+    assert!(name.error().is_some());
+
+    // 失去过焦点后每次变更都会校验
+    name.set_value_string("leptos").unwrap();
+    assert_eq!(name.error(), None);
+    name.set_value_string("").unwrap();
+    assert!(name.error().is_some());
+}
+
+#[test]
+fn on_touched_reveals_errors_after_first_blur() {
+    let runtime = create_runtime();
+    assert_on_touched(OnTouchedControls::new(OnTouched::default()));
+    assert_on_touched(OnTouchedSingleControls::new(OnTouchedSingle::default()));
+    runtime.dispose();
+}
+
+fn assert_on_submit<C: Controls>(controls: C) {
+    let name = field(&controls, "name");
+    name.set_value_string("leptos").unwrap();
+    name.set_value_string("").unwrap();
+    name.touch();
+    assert_eq!(name.error(), None);
+
+    assert_eq!(controls.validate().len(), 1);
+    assert!(name.error().is_some());
+
+    // 提交之后变更时重新校验
+    name.set_value_string("leptos").unwrap();
+    assert_eq!(name.error(), None);
+    assert_eq!(controls.errors(), Vec::<std::borrow::Cow<str>>::new());
+}
+
+#[test]
+fn on_submit_reveals_errors_after_validate() {
+    let runtime = create_runtime();
+    assert_on_submit(OnSubmitControls::new(OnSubmit::default()));
+    assert_on_submit(OnSubmitSingleControls::new(OnSubmitSingle::default()));
+    runtime.dispose();
+}
+
+#[test]
+fn revalidate_reveals_errors_before_submit() {
+    let runtime = create_runtime();
+    let controls = OnChangeControls::new(OnChange::default());
+    controls.name.revalidate();
+    assert!(controls.name.error().is_some());
+    runtime.dispose();
+}
+
+#[test]
+fn field_state_is_created_in_the_field_owner() {
+    let runtime = create_runtime();
+    let controls = OnChangeControls::new(OnChange::default());
+    controls.name.set(String::new());
+
+    // 在子组件中第一次读取状态,子组件销毁后状态仍然可用
+    let (error, disposer) = child(move || controls.name.error());
+    assert!(error.is_some());
+    drop(disposer);
+    assert!(controls.name.is_dirty());
+    assert!(controls.name.error().is_some());
+    controls.name.set(String::from("leptos"));
+    assert_eq!(controls.name.error(), None);
+
+    // 状态随控制器一起销毁
+    controls.dispose();
+    assert!(controls.is_disposed());
+    assert_eq!(controls.name.try_get_untracked(), None);
+    runtime.dispose();
+}
+
+#[test]
+fn standalone_field_has_no_form_state() {
+    let runtime = create_runtime();
+    let field = RwSignalField::<OnChangeName, String>::new(String::new());
+    assert!(field.state().form().is_none());
+    field.set(String::from("leptos"));
+    assert!(field.is_dirty());
+
+    let controls = OnChangeControls::new(OnChange::default());
+    assert!(controls.name.state().form().is_some());
+    runtime.dispose();
+}
@@ -0,0 +1,126 @@
+use leptos::*;
+use leptos_controls::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+thread_local! {
+    static TITLES: RefCell<Vec<(String, Option<String>)>> = const { RefCell::new(Vec::new()) };
+}
+
+#[allow(clippy::ptr_arg)]
+fn record(new: &String, old: Option<&String>) {
+    TITLES.with_borrow_mut(|titles| titles.push((new.clone(), old.cloned())));
+}
+
+#[derive(Clone, Default, Controls)]
+struct Post {
+    #[field(on_change = record)]
+    title: String,
+    likes: u32,
+}
+
+#[derive(Clone, Default, Controls)]
+#[controls(storage = "single")]
+struct PostSingle {
+    title: String,
+    likes: u32,
+}
+
+type Changes<T> = Rc<RefCell<Vec<(T, Option<T>)>>>;
+
+///
+/// 记录监听回调的参数
+///
+fn recorder<T: Clone + 'static>() -> (Changes<T>, impl Fn(&T, Option<&T>) + 'static) {
+    let values = Rc::new(RefCell::new(Vec::new()));
+    let f = {
+        let values = values.clone();
+        move |new: &T, old: Option<&T>| values.borrow_mut().push((new.clone(), old.cloned()))
+    };
+    (values, f)
+}
+
+#[test]
+fn watch_skips_initial_value() {
+    let runtime = create_runtime();
+    let controls = PostControls::new(Post::default());
+    let (likes, f) = recorder();
+    let stop = controls.likes.watch(f);
+    assert!(likes.borrow().is_empty());
+
+    controls.likes.set(1);
+    controls.likes.update(|likes| *likes += 1);
+    assert_eq!(*likes.borrow(), vec![(1, Some(0)), (2, Some(1))]);
+
+    stop();
+    controls.likes.set(3);
+    assert_eq!(likes.borrow().len(), 2);
+    runtime.dispose();
+}
+
+#[test]
+fn watch_immediate_runs_with_initial_value() {
+    let runtime = create_runtime();
+    let controls = PostSingleControls::new(PostSingle::default());
+    let (likes, f) = recorder();
+    let stop = controls.likes.watch_immediate(f);
+    assert_eq!(*likes.borrow(), vec![(0, None)]);
+
+    // 修改其他字段不会触发
+    controls.title.set(String::from("leptos"));
+    controls.likes.set(1);
+    assert_eq!(*likes.borrow(), vec![(0, None), (1, Some(0))]);
+
+    stop();
+    controls.likes.set(2);
+    assert_eq!(likes.borrow().len(), 2);
+    runtime.dispose();
+}
+
+#[test]
+fn on_change_receives_new_and_old_values() {
+    let runtime = create_runtime();
+    let controls = PostControls::new(Post {
+        title: String::from("leptos"),
+        likes: 0,
+    });
+    controls.likes.set(1);
+    controls.title.set(String::from("controls"));
+    assert_eq!(
+        TITLES.with_borrow(Clone::clone),
+        vec![(String::from("controls"), Some(String::from("leptos")))]
+    );
+    runtime.dispose();
+}
+
+fn assert_watch_any<C: Controls>(controls: C, stop: impl Fn(), names: Rc<RefCell<Vec<&str>>>) {
+    let title = controls.get_field("title").unwrap();
+    let likes = controls.get_field("likes").unwrap();
+    title.set_value_string("leptos").unwrap();
+    likes.set_value_string("1").unwrap();
+    assert_eq!(*names.borrow(), vec!["title", "likes"]);
+
+    stop();
+    title.set_value_string("controls").unwrap();
+    assert_eq!(names.borrow().len(), 2);
+}
+
+#[test]
+fn watch_any_reports_changed_field_names() {
+    let runtime = create_runtime();
+    let names = Rc::new(RefCell::new(Vec::new()));
+    let push = {
+        let names = names.clone();
+        move |name, _: &dyn Field| names.borrow_mut().push(name)
+    };
+
+    let controls = PostControls::new(Post::default());
+    let stop = controls.watch_any(push.clone());
+    assert_watch_any(controls, stop, names.clone());
+
+    names.borrow_mut().clear();
+    let controls = PostSingleControls::new(PostSingle::default());
+    let stop = controls.watch_any(push);
+    assert_watch_any(controls, stop, names);
+    runtime.dispose();
+}
@@ -0,0 +1,95 @@
+use leptos::*;
+use leptos_controls::*;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum Level {
+    #[default]
+    Low,
+    High,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Low => f.write_str("low"),
+            Level::High => f.write_str("high"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "low" => Ok(Level::Low),
+            "high" => Ok(Level::High),
+            _ => Err(format!("unknown level `{}`", value)),
+        }
+    }
+}
+
+impl SelectOptions for Level {
+    fn options() -> Vec<Self> {
+        vec![Level::Low, Level::High]
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Tags(Vec<String>);
+
+#[derive(Clone, Default, Controls)]
+struct Task {
+    title: String,
+    #[field(widget = "textarea")]
+    content: String,
+    estimate: f32,
+    done: bool,
+    level: Level,
+    #[field(widget = "select")]
+    priority: Level,
+    tags: Tags,
+}
+
+#[test]
+fn widgets_are_inferred_from_field_types() {
+    let runtime = create_runtime();
+    let controls = TaskControls::new(Task::default());
+    let widgets = controls
+        .fields()
+        .map(|(_, field)| field.widget())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        widgets,
+        vec![
+            Widget::Text,
+            Widget::TextArea,
+            Widget::Number,
+            Widget::Checkbox,
+            Widget::Select,
+            Widget::Select,
+            Widget::Text,
+        ]
+    );
+    runtime.dispose();
+}
+
+#[test]
+fn select_fields_list_options() {
+    let runtime = create_runtime();
+    let controls = TaskControls::new(Task::default());
+    let level = controls.field(TaskField::Level);
+    assert_eq!(level.options(), vec!["low", "high"]);
+    assert_eq!(
+        controls.field(TaskField::Priority).options(),
+        level.options()
+    );
+    assert!(controls.field(TaskField::Tags).options().is_empty());
+
+    level.set_value_string("high").unwrap();
+    assert_eq!(controls.level.get_untracked(), Level::High);
+    assert!(level.set_value_string("middle").is_err());
+    runtime.dispose();
+}